@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Where File > Export writes the generated config
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportSettings {
+    /// Root folder of the pack, ex: plugins/MythicMobs/Packs/MyPack
+    pub pack_dir: String,
+    /// File inside the folder of the entry, ex: `Mobs/`, the internal name is used when empty
+    pub file_name: String,
+}
+
+impl ExportSettings {
+    /// Path of the file to write for an entry of the pack folder `folder`
+    pub fn file_path(&self, folder: &str, internal_name: &str) -> PathBuf {
+        let file_name = if self.file_name.trim().is_empty() {
+            format!("{internal_name}.yml")
+        } else if self.file_name.ends_with(".yml") || self.file_name.ends_with(".yaml") {
+            self.file_name.trim().to_owned()
+        } else {
+            format!("{}.yml", self.file_name.trim())
+        };
+        Path::new(&self.pack_dir).join(folder).join(file_name)
+    }
+}
+
+/// Write `section` into the file at `path`, replacing the entry named `name`
+/// and keeping every other entry of the file as it was
+pub fn write_section(path: &Path, name: &str, section: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {e}", dir.display()))?;
+    }
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Could not read {}: {e}", path.display())),
+    };
+    std::fs::write(path, merge_section(&content, name, section))
+        .map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+/// Replace the top level entry `name` of a yaml document by `section`,
+/// or append `section` if there is no such entry
+pub fn merge_section(content: &str, name: &str, section: &str) -> String {
+    let section = section.trim_end();
+    let mut merged = String::new();
+    let mut replaced = false;
+    let mut skipping = false;
+    // Blank lines and comments at the end of a replaced entry belong to the next one
+    let mut pending = String::new();
+    for line in content.lines() {
+        if let Some(key) = top_level_key(line) {
+            if skipping {
+                merged.push_str(&pending);
+            }
+            pending.clear();
+            skipping = key == name;
+            if skipping && !replaced {
+                merged.push_str(section);
+                merged.push('\n');
+                replaced = true;
+            }
+        }
+        if !skipping {
+            merged.push_str(line);
+            merged.push('\n');
+        } else if line.trim().is_empty() || line.starts_with('#') {
+            pending.push_str(line);
+            pending.push('\n');
+        } else {
+            pending.clear();
+        }
+    }
+    if !replaced {
+        let kept = merged.trim_end().len();
+        merged.truncate(kept);
+        if !merged.is_empty() {
+            merged.push_str("\n\n");
+        }
+        merged.push_str(section);
+        merged.push('\n');
+    }
+    merged
+}
+
+/// Internal name of a line starting a top level entry
+fn top_level_key(line: &str) -> Option<&str> {
+    if line.starts_with(char::is_whitespace) || line.starts_with('#') || line.starts_with('-') {
+        return None;
+    }
+    let (key, _) = line.split_once(':')?;
+    Some(key.trim().trim_matches(|c| c == '"' || c == '\''))
+}
+
+/// Single quoted yaml scalar, so that color codes like `&c` stay a string
+pub fn yaml_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
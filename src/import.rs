@@ -0,0 +1,405 @@
+use std::path::Path;
+
+use serde_yaml::Mapping;
+use strum::{IntoEnumIterator, VariantArray};
+
+use crate::{
+    skill::{
+        Skill,
+        mechanics::Mechanic,
+        minecraft_lib::MinecraftMob,
+        modifiers::{HealthModifier, MechanicOptions},
+        parser::{Argument, omit_empty_args, parse_skill_line},
+        targeters::{
+            LocationTarget, MultyEntity, SingleEntityTarget, TargetSort, Targeter, TargeterOption,
+            TargeterOptions, ThreatTableTarget,
+        },
+        triggers::Trigger,
+    },
+    states::{mob::Mob, skill_list::SkillList},
+};
+
+/// Keys of a mob section the importer reads, the others are reported as not imported
+const IMPORTED_KEYS: [&str; 6] = ["Type", "Display", "Health", "Damage", "Armor", "Skills"];
+
+/// Read a MythicMobs `Mobs/*.yml` file, see parse_mobs
+pub fn read_mob_file(path: impl AsRef<Path>) -> Result<(Vec<Mob>, Vec<String>), String> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    parse_mobs(&content).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Parse the content of a mob file, with the `Mob.Key` of every key that is not imported
+pub fn parse_mobs(content: &str) -> Result<(Vec<Mob>, Vec<String>), String> {
+    let root: Mapping = match serde_yaml::from_str(content) {
+        Ok(serde_yaml::Value::Mapping(root)) => root,
+        Ok(serde_yaml::Value::Null) => return Ok((Vec::new(), Vec::new())),
+        Ok(_) => return Err("The file is not a list of mobs".to_owned()),
+        Err(e) => return Err(format!("Invalid yaml: {e}")),
+    };
+    let mut ignored = Vec::new();
+    let mobs = root
+        .iter()
+        .map(|(name, mob)| {
+            let name = name
+                .as_str()
+                .ok_or_else(|| "A mob internal name is not a string".to_owned())?;
+            let mob = mob
+                .as_mapping()
+                .ok_or_else(|| format!("{name} is not a mob section"))?;
+            mob.keys()
+                .map(yaml_to_string)
+                .filter(|key| !IMPORTED_KEYS.contains(&key.as_str()))
+                .for_each(|key| ignored.push(format!("{name}.{key}")));
+            parse_mob(name, mob)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((mobs, ignored))
+}
+
+fn parse_mob(name: &str, section: &Mapping) -> Result<Mob, String> {
+    let mut mob = Mob {
+        mob_name: name.to_owned(),
+        ..Default::default()
+    };
+    if let Some(mob_type) = section.get("Type") {
+        let mob_type = yaml_to_string(mob_type);
+        mob.mob_type = mob_type_from_str(&mob_type)
+            .ok_or_else(|| format!("{name}: unknown mob type {mob_type}"))?;
+    }
+    if let Some(display) = section.get("Display") {
+        mob.mob_display_name = yaml_to_string(display);
+    }
+    if let Some(health) = section.get("Health").and_then(|v| v.as_f64()) {
+        mob.mob_health = health.round().clamp(0.0, u32::MAX as f64) as u32;
+    }
+    if let Some(damage) = section.get("Damage").and_then(|v| v.as_f64()) {
+        mob.damage = damage.round().clamp(0.0, u8::MAX as f64) as u8;
+    }
+    if let Some(armor) = section.get("Armor").and_then(|v| v.as_f64()) {
+        mob.armor = armor.round().clamp(0.0, u8::MAX as f64) as u8;
+    }
+    if let Some(skills) = section.get("Skills") {
+        let skills = skills
+            .as_sequence()
+            .ok_or_else(|| format!("{name}: Skills must be a list"))?;
+        mob.skills = skills
+            .iter()
+            .enumerate()
+            .map(|(id, line)| {
+                skill_from_line(&yaml_to_string(line)).set_name(format!("Skill_{id}"))
+            })
+            .collect();
+    }
+    mob.select_first_skill();
+    Ok(mob)
+}
+
+fn yaml_to_string(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// MythicMobs accepts `WITHER_SKELETON` as well as `WitherSkeleton`
+fn mob_type_from_str(mob_type: &str) -> Option<MinecraftMob> {
+    let normalize = |s: &str| s.replace('_', "").to_ascii_lowercase();
+    let mob_type = normalize(mob_type);
+    MinecraftMob::VARIANTS
+        .iter()
+        .find(|v| normalize(&v.to_string()) == mob_type)
+        .cloned()
+}
+
+/// Map a skill line to a Skill, keeping the whole line in raw_args
+/// when any part of it can't be represented by the editor
+pub fn skill_from_line(line: &str) -> Skill {
+    let line = line.trim();
+    let line = line.strip_prefix("- ").unwrap_or(line).trim();
+    try_skill_from_line(line).unwrap_or_else(|| Skill {
+        raw_args: line.to_owned(),
+        ..Default::default()
+    })
+}
+
+fn try_skill_from_line(line: &str) -> Option<Skill> {
+    let line = parse_skill_line(line).ok()?;
+    if let Some(ticks) = line.delay {
+        return Some(Skill {
+            mechanic: Some(Mechanic::Delay(ticks)),
+            ..Default::default()
+        });
+    }
+    if !line.conditions.is_empty() {
+        return None;
+    }
+    let args = args_to_pairs(&line.args);
+    // Mechanics like setitemgroupcooldown have arguments named like the options
+    let (mechanic, options) = match mechanic_from_args(&line.mechanic, &args) {
+        Some(mechanic) => (mechanic, MechanicOptions::default()),
+        None => {
+            let mut options = MechanicOptions::default();
+            let args: Vec<(String, String)> = args
+                .into_iter()
+                .filter(|(key, value)| !options.set_arg(key, value))
+                .collect();
+            (mechanic_from_args(&line.mechanic, &args)?, options)
+        }
+    };
+    let mut skill = Skill {
+        mechanic: Some(mechanic),
+        options,
+        chance: line.chance,
+        ..Default::default()
+    };
+    if let Some(health_modifier) = &line.health_modifier {
+        skill.health_modifier = Some(HealthModifier::parse(health_modifier)?);
+    }
+    if let Some(targeter) = &line.targeter {
+        skill.targeter = targeter_from_str(&format!("@{}", targeter.name))?;
+        let accepted = match &skill.targeter {
+            Targeter::SingleEntity(target, _) => target.options(),
+            Targeter::MultyEntity(target, _) => target.options(),
+            Targeter::ThreatTable(target, _) => target.options(),
+            _ => &[],
+        };
+        let options = targeter_options(&args_to_pairs(&targeter.args), accepted)?;
+        match &mut skill.targeter {
+            Targeter::SingleEntity(_, o)
+            | Targeter::MultyEntity(_, o)
+            | Targeter::ThreatTable(_, o) => *o = options,
+            _ if targeter.args.is_empty() => {}
+            _ => return None,
+        }
+    }
+    if let Some(trigger) = &line.trigger {
+        skill.trigger = trigger_from_str(&format!("~{}", trigger.name))?;
+        if let Some(arg) = &trigger.arg
+            && !skill.trigger.set_arg(arg)
+        {
+            return None;
+        }
+    }
+    Some(skill)
+}
+
+fn args_to_pairs(args: &[Argument]) -> Vec<(String, String)> {
+    args.iter()
+        .map(|a| (a.key.clone(), a.value.to_string()))
+        .collect()
+}
+
+/// Options of a targeter, as long as the targeter accepts every one of them
+fn targeter_options(
+    args: &[(String, String)],
+    accepted: &[TargeterOption],
+) -> Option<TargeterOptions> {
+    let mut options = TargeterOptions::default();
+    for (key, value) in args {
+        let option = match key.to_ascii_lowercase().as_str() {
+            "r" | "radius" => TargeterOption::Radius,
+            "minr" | "minradius" => TargeterOption::MinRadius,
+            "maxr" | "maxradius" => TargeterOption::MaxRadius,
+            "a" | "angle" => TargeterOption::Angle,
+            "range" => TargeterOption::Range,
+            "rot" | "rotation" => TargeterOption::Rotation,
+            "t" | "type" | "types" => TargeterOption::Types,
+            "limit" => TargeterOption::Limit,
+            "sort" => TargeterOption::Sort,
+            "ignore" => TargeterOption::Ignore,
+            _ => return None,
+        };
+        if !accepted.contains(&option) {
+            return None;
+        }
+        match option {
+            TargeterOption::Radius => options.radius = Some(value.parse().ok()?),
+            TargeterOption::MinRadius => options.min_radius = Some(value.parse().ok()?),
+            TargeterOption::MaxRadius => options.max_radius = Some(value.parse().ok()?),
+            TargeterOption::Angle => options.angle = Some(value.parse().ok()?),
+            TargeterOption::Range => options.range = Some(value.parse().ok()?),
+            TargeterOption::Rotation => options.rotation = Some(value.parse().ok()?),
+            TargeterOption::Types => options.types = Some(value.clone()),
+            TargeterOption::Limit => options.limit = Some(value.parse().ok()?),
+            TargeterOption::Sort => {
+                options.sort =
+                    Some(TargetSort::iter().find(|v| v.to_string().eq_ignore_ascii_case(value))?)
+            }
+            TargeterOption::Ignore => options.ignore = Some(value.clone()),
+            TargeterOption::Conditions => return None,
+        }
+    }
+    Some(options)
+}
+
+fn targeter_from_str(token: &str) -> Option<Targeter> {
+    if let Some(t) = SingleEntityTarget::VARIANTS
+        .iter()
+        .find(|v| v.to_string().eq_ignore_ascii_case(token))
+    {
+        return Some(t.clone().into());
+    }
+    if let Some(t) = MultyEntity::VARIANTS
+        .iter()
+        .find(|v| v.to_string().eq_ignore_ascii_case(token))
+    {
+        return Some(t.clone().into());
+    }
+    if let Some(t) = ThreatTableTarget::VARIANTS
+        .iter()
+        .find(|v| v.to_string().eq_ignore_ascii_case(token))
+    {
+        return Some(t.clone().into());
+    }
+    // Location targeters with parameters are kept in raw_args
+    LocationTarget::iter()
+        .find(|v| v.to_string().eq_ignore_ascii_case(token))
+        .map(Targeter::from)
+}
+
+fn trigger_from_str(token: &str) -> Option<Trigger> {
+    Trigger::iter()
+        .filter(|v| !matches!(v, Trigger::None))
+        .find(|v| {
+            let rendered = v.to_string();
+            let name = rendered.split(':').next().unwrap_or_default();
+            name.eq_ignore_ascii_case(token)
+        })
+}
+
+/// `effect:particle`, `e:particle` and `particle` are the same mechanic
+fn same_mechanic_name(a: &str, b: &str) -> bool {
+    let strip = |s: &str| {
+        let lower = s.to_ascii_lowercase();
+        lower
+            .strip_prefix("effect:")
+            .or_else(|| lower.strip_prefix("e:"))
+            .map(str::to_owned)
+            .unwrap_or(lower)
+    };
+    strip(a) == strip(b)
+}
+
+/// Name of a rendered mechanic
+fn rendered_name(mechanic: &Mechanic) -> Option<String> {
+    // Empty values are not valid arguments, the parser would reject the line
+    let line = parse_skill_line(&omit_empty_args(&mechanic.to_string())).ok()?;
+    Some(line.mechanic)
+}
+
+/// Rebuild a mechanic from the arguments of a skill line
+pub fn mechanic_from_args(name: &str, args: &[(String, String)]) -> Option<Mechanic> {
+    Mechanic::iter()
+        .map(Mechanic::with_defaults)
+        .filter(|m| rendered_name(m).is_some_and(|n| same_mechanic_name(&n, name)))
+        .find_map(|mut mechanic| {
+            args.iter()
+                .all(|(key, value)| mechanic.set_arg(key, value))
+                .then_some(mechanic)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::mechanics::{VariableScope, VariableType};
+
+    #[test]
+    fn imports_metaskill_references() {
+        let skill = skill_from_line("- skill{s=Fireball} @target");
+        assert_eq!(
+            skill.config_line(false).as_deref(),
+            Some("- skill{s=Fireball} @Target")
+        );
+        assert!(matches!(skill.mechanic, Some(Mechanic::Skill { .. })));
+        let skill = skill_from_line("- sudoskill{s=Jump}");
+        assert!(matches!(skill.mechanic, Some(Mechanic::SudoSkill { .. })));
+    }
+
+    #[test]
+    fn every_mechanic_round_trips() {
+        // Mechanics with an argument that is not a plain value are kept in raw_args
+        let kept_raw = [
+            "Add Trade",
+            "BlockMask",
+            "BlockUnmask",
+            "BlockWave",
+            "Equip",
+            "HealPercent",
+            "Shoot",
+            "StopSoundWithCategory",
+            "TeleportTo",
+            "Time",
+            "ThunderLevel",
+            "Velocity",
+            "Weather",
+        ];
+        for mechanic in Mechanic::iter().map(Mechanic::with_defaults) {
+            let line = omit_empty_args(&mechanic.to_string());
+            if mechanic.missing_value().is_some() || parse_skill_line(&line).is_err() {
+                continue;
+            }
+            let name: String = mechanic.get_fields().into();
+            let skill = skill_from_line(&line);
+            if kept_raw.contains(&name.as_str()) {
+                assert!(skill.mechanic.is_none(), "{line}");
+                assert_eq!(skill.raw_args, line.trim_start_matches("- "));
+            } else {
+                assert!(skill.mechanic == Some(mechanic), "{line}");
+            }
+        }
+    }
+
+    #[test]
+    fn reads_arguments_under_their_full_name() {
+        let skill = skill_from_line("- arrowvolley{Amount=3;fireTicks=20;s=5}");
+        assert_eq!(
+            skill.config_line(false).as_deref(),
+            Some("- arrowvolley{a=3;s=5;v=0;f=20;rd=0;pickup=false}")
+        );
+        let skill = skill_from_line("- setvariable{var=skill.count;type=float;val=2}");
+        assert!(matches!(
+            skill.mechanic,
+            Some(Mechanic::SetVariable {
+                scope: VariableScope::Skill,
+                variable_type: VariableType::Float,
+                ..
+            })
+        ));
+        assert_eq!(
+            skill.config_line(false).as_deref(),
+            Some("- setvariable{var=skill.count;type=FLOAT;value=2}")
+        );
+        // Unknown arguments and values that don't fit keep the line in raw_args
+        assert!(skill_from_line("- arrowvolley{a=many}").mechanic.is_none());
+        assert!(
+            skill_from_line("- arrowvolley{unknown=1}")
+                .mechanic
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn reports_keys_that_are_not_imported() {
+        let (mobs, ignored) =
+            parse_mobs("Boss:\n  Type: ZOMBIE\n  Health: 50\n  Options:\n    Silent: true\n  Drops:\n  - exp 5").unwrap();
+        assert_eq!(mobs.len(), 1);
+        assert_eq!(mobs[0].mob_health, 50);
+        assert_eq!(ignored, vec!["Boss.Options", "Boss.Drops"]);
+    }
+
+    #[test]
+    fn delay_round_trips() {
+        let skill = skill_from_line("- delay 20");
+        assert!(matches!(skill.mechanic, Some(Mechanic::Delay(20))));
+        assert_eq!(skill.config_line(true).as_deref(), Some("- delay 20"));
+        // Targeter, conditions and modifiers of a delay are not written
+        let mut skill = skill_from_line("- damage{a=1} @self ?burning <50% 0.5");
+        skill.mechanic = Some(Mechanic::Delay(20));
+        assert_eq!(skill.config_line(true).as_deref(), Some("- delay 20"));
+    }
+}
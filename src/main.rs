@@ -1,5 +1,7 @@
 use states::AppState;
 
+mod export;
+mod import;
 mod skill;
 mod states;
 mod ui;
@@ -1,14 +1,18 @@
+use conditions::InlineCondition;
 use mechanics::Mechanic;
+use modifiers::{HealthModifier, MechanicOptions};
 use serde::{Deserialize, Serialize};
 use targeters::Targeter;
 use triggers::Trigger;
 
 pub mod conditions;
 pub mod mechanics;
+pub mod modifiers;
 pub mod params;
+pub mod parser;
 pub mod targeters;
 pub mod triggers;
-pub mod ui {}
+pub mod ui;
 pub mod minecraft_lib;
 
 #[derive(Clone, Serialize, Deserialize, Default)]
@@ -17,7 +21,27 @@ pub struct Skill {
     pub mechanic: Option<Mechanic>,
     pub raw_args: String,
     pub targeter: Targeter,
+    /// Inline conditions, written after the targeter
+    #[serde(default)]
+    pub conditions: Vec<InlineCondition>,
     pub trigger: Trigger,
+    /// delay, repeat, cooldown... written with the mechanic arguments
+    #[serde(default)]
+    pub options: MechanicOptions,
+    /// Health range of the caster, written after the trigger
+    #[serde(default)]
+    pub health_modifier: Option<HealthModifier>,
+    /// Chance for the skill to run, from 0 to 1
+    #[serde(default)]
+    pub chance: Option<f32>,
+}
+
+/// Names of the project entries offered by the skill editor pickers
+#[derive(Default)]
+pub struct ProjectNames {
+    pub metaskills: Vec<String>,
+    pub spawners: Vec<String>,
+    pub spawner_groups: Vec<String>,
 }
 
 impl Skill {
@@ -31,4 +55,46 @@ impl Skill {
         self.name = name.into();
         self
     }
+    /// The skill as a line of a `Skills:` list, None when there is nothing to write or
+    /// when the mechanic misses a required value
+    pub fn config_line(&self, with_trigger: bool) -> Option<String> {
+        let Some(mech) = &self.mechanic else {
+            return (!self.raw_args.is_empty()).then(|| format!("- {}", self.raw_args));
+        };
+        if mech.missing_value().is_some() {
+            return None;
+        }
+        // A delay takes nothing but its ticks
+        if let Mechanic::Delay(_) = mech {
+            return Some(mech.to_string());
+        }
+        Some(format!(
+            "{}{}{}{}{}{}",
+            self.options.apply(&mech.to_string()),
+            if let Targeter::None = self.targeter {
+                "".to_owned()
+            } else {
+                format!(" {}", self.targeter)
+            },
+            self.conditions
+                .iter()
+                .map(|c| format!(" {c}"))
+                .collect::<String>(),
+            if !with_trigger || matches!(self.trigger, Trigger::None) {
+                "".to_owned()
+            } else {
+                format!(" {}", self.trigger.config())
+            },
+            if let Some(health_modifier) = &self.health_modifier {
+                format!(" {health_modifier}")
+            } else {
+                "".to_owned()
+            },
+            if let Some(chance) = self.chance {
+                format!(" {chance}")
+            } else {
+                "".to_owned()
+            },
+        ))
+    }
 }
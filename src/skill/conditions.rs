@@ -1,227 +1,1882 @@
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, strum::Display)]
+use super::{
+    params::{BiomeList, NumberRange},
+    parser::omit_empty_args,
+};
+
+#[derive(
+    Clone, Debug, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter, Default,
+)]
 pub enum Condition {
-    #[strum(to_string = "Altitude", serialize = "- altitude{{}}")]
     /// Tests how far above the ground the target entity is
+    #[strum(to_string = "altitude{{h={height};mh={max_height}}}")]
     Altitude {
         /// The height range to check
-        height: u8,
-        /// Limits the maximum height this condition can checks for
-        /// Default 30
+        height: NumberRange,
+        /// Limits the maximum height this condition can checks for, default 30
         max_height: u8,
     },
-    #[strum(to_string = "Biome", serialize = "- biome{{b={biome}}} {exact}")]
     /// Tests if the target is within the given list of biomes
+    #[strum(to_string = "biome{{b={biome};exact={exact}}}")]
     Biome {
         /// A list of biomes to check
-        biome: String,
+        biome: BiomeList,
         /// Whether to match the biome exactly
         exact: bool,
     },
-    #[strum(
-        to_string = "Biome Type",
-        serialize = "- biometype{{t={biome_type}}} {exact}"
-    )]
-    /// Tests for the biome category at a location.
+    /// Tests for the biome category at a location
+    #[strum(to_string = "biometype{{t={biome_type};exact={exact}}}")]
     BiomeType {
-        /// A list of biomes to check
-        /// Biome Types:
-        /// none
-        /// taiga
-        /// extreme_hills
-        /// jungle
-        /// mesa
-        /// plains
-        /// savanna
-        /// icy
-        /// the_end
-        /// beach
-        /// forest
-        /// ocean
-        /// desert
-        /// river
-        /// swamp
-        /// mushroom
-        /// nether
-        /// underground
-        /// mountain
+        /// A list of biome types to check: none, taiga, extreme_hills, jungle, mesa, plains, savanna, icy, the_end, beach, forest, ocean, desert, river, swamp, mushroom, nether, underground, mountain
         biome_type: String,
         /// Whether to match the type exactly
         exact: bool,
     },
-    BlockType,
-    BlockTypeInRadius,
+    /// Tests the material type of the target block
+    #[strum(to_string = "blocktype{{t={types}}}")]
+    BlockType {
+        /// A list of materials to check
+        types: String,
+    },
+    /// Tests the amount of blocks of the given types around the target
+    #[strum(to_string = "blocktypeinradius{{t={types};r={radius};a={amount}}}")]
+    BlockTypeInRadius {
+        /// A list of materials to check
+        types: String,
+        /// The radius to check in
+        radius: f32,
+        /// The amount of blocks to match
+        amount: NumberRange,
+    },
+    /// Whether the target is blocking with a shield
+    #[strum(to_string = "blocking")]
     Blocking,
+    /// Whether the bounding boxes of the caster and the target overlap
+    #[strum(to_string = "boundingboxesoverlap")]
     BoundingBoxesOverlap,
-    BowTension,
+    /// Tests the tension of the bow the target player is drawing
+    #[strum(to_string = "bowtension{{t={tension}}}")]
+    BowTension {
+        /// The tension range, from 0 to 1
+        tension: NumberRange,
+    },
+    /// Whether the target is on fire
+    #[strum(to_string = "burning")]
+    #[default]
     Burning,
-    Chance,
+    /// Passes with the given chance
+    #[strum(to_string = "chance{{c={chance}}}")]
+    Chance {
+        /// The chance to pass, from 0 to 1, default 0.5
+        chance: f32,
+    },
+    /// Whether the target creeper is charged
+    #[strum(to_string = "charged")]
     Charged,
-    Children,
-    Color,
-    CompareValues,
+    /// Tests the amount of children the caster has
+    #[strum(to_string = "children{{a={amount}}}")]
+    Children {
+        /// The amount of children
+        amount: NumberRange,
+    },
+    /// Tests the color of the target sheep, shulker or wolf collar
+    #[strum(to_string = "color{{c={color}}}")]
+    Color {
+        /// The color to check
+        color: String,
+    },
+    /// Compares two values, ex: `<caster.var.a> > 5`
+    #[strum(to_string = "comparevalues{{v={value}}}")]
+    CompareValues {
+        /// The comparison to evaluate
+        value: String,
+    },
+    /// Whether the target is crouching
+    #[strum(to_string = "crouching")]
     Crouching,
-    Cuboid,
-    DamageAmount,
-    DamageCause,
-    DamageTag,
+    /// Whether the target is inside the cuboid between two points
+    #[strum(to_string = "cuboid{{a={point_a};b={point_b}}}")]
+    Cuboid {
+        /// The first corner, as x,y,z
+        point_a: String,
+        /// The second corner, as x,y,z
+        point_b: String,
+    },
+    /// Tests the amount of damage of the damage event
+    #[strum(to_string = "damageamount{{a={amount}}}")]
+    DamageAmount {
+        /// The amount of damage
+        amount: NumberRange,
+    },
+    /// Tests the cause of the damage event
+    #[strum(to_string = "damagecause{{c={cause}}}")]
+    DamageCause {
+        /// The damage cause, ex: ENTITY_ATTACK
+        cause: String,
+    },
+    /// Whether the damage event has the given tag
+    #[strum(to_string = "damagetag{{t={tag}}}")]
+    DamageTag {
+        /// The damage tag
+        tag: String,
+    },
+    /// Whether it is dawn, from 22000 to 2000 ticks
+    #[strum(to_string = "dawn")]
     Dawn,
+    /// Whether it is day, from 2000 to 10000 ticks
+    #[strum(to_string = "day")]
     Day,
-    Dimension,
-    DirectionalVelocity,
-    Distance,
-    DistanceFromLocation,
-    DistanceFromPin,
-    DistanceFromSpawn,
-    DistanceFromTrackedLocation,
+    /// Tests the dimension the target is in
+    #[strum(to_string = "dimension{{d={dimension}}}")]
+    Dimension {
+        /// NORMAL, NETHER or THE_END
+        dimension: String,
+    },
+    /// Tests the velocity of the target on each axis
+    #[strum(to_string = "directionalvelocity{{x={x};y={y};z={z}}}")]
+    DirectionalVelocity {
+        /// The velocity on the x axis
+        x: NumberRange,
+        /// The velocity on the y axis
+        y: NumberRange,
+        /// The velocity on the z axis
+        z: NumberRange,
+    },
+    /// Tests the distance between the caster and the target
+    #[strum(to_string = "distance{{d={distance}}}")]
+    Distance {
+        /// The distance range
+        distance: NumberRange,
+    },
+    /// Tests the distance between the target and a location
+    #[strum(to_string = "distancefromlocation{{w={world};l={location};d={distance}}}")]
+    DistanceFromLocation {
+        /// The world of the location
+        world: String,
+        /// The location, as x,y,z
+        location: String,
+        /// The distance range
+        distance: NumberRange,
+    },
+    /// Tests the distance between the target and a pin
+    #[strum(to_string = "distancefrompin{{p={pin};d={distance}}}")]
+    DistanceFromPin {
+        /// The name of the pin
+        pin: String,
+        /// The distance range
+        distance: NumberRange,
+    },
+    /// Tests the distance between the target and the world spawn
+    #[strum(to_string = "distancefromspawn{{d={distance}}}")]
+    DistanceFromSpawn {
+        /// The distance range
+        distance: NumberRange,
+    },
+    /// Tests the distance between the target and the tracked location of the caster
+    #[strum(to_string = "distancefromtrackedlocation{{d={distance}}}")]
+    DistanceFromTrackedLocation {
+        /// The distance range
+        distance: NumberRange,
+    },
+    /// Whether it is dusk, from 14000 to 18000 ticks
+    #[strum(to_string = "dusk")]
     Dusk,
-    EnchantingExperience,
-    EnchantingLevel,
+    /// Tests the experience points of the target player
+    #[strum(to_string = "enchantingexperience{{a={amount}}}")]
+    EnchantingExperience {
+        /// The amount of experience
+        amount: NumberRange,
+    },
+    /// Tests the experience level of the target player
+    #[strum(to_string = "enchantinglevel{{l={level}}}")]
+    EnchantingLevel {
+        /// The experience level
+        level: NumberRange,
+    },
+    /// Whether an ender dragon is alive in the world
+    #[strum(to_string = "enderdragonalive")]
     EnderDragonAlive,
-    EnderDragonPhase,
-    EntityItemIsSimilar,
-    EntityItemType,
-    EntityMaterialType,
-    EntityType,
-    Faction,
-    FallSpeed,
-    FieldOfView,
-    FoodLevel,
-    FoodSaturation,
-    Gamemode,
+    /// Tests the phase of the target ender dragon
+    #[strum(to_string = "enderdragonphase{{p={phase}}}")]
+    EnderDragonPhase {
+        /// A list of phases, ex: CIRCLING,STRAFING
+        phase: String,
+    },
+    /// Whether the target item entity is similar to the given item
+    #[strum(to_string = "entityitemissimilar{{i={item}}}")]
+    EntityItemIsSimilar {
+        /// The item to compare to
+        item: String,
+    },
+    /// Tests the type of the target item entity
+    #[strum(to_string = "entityitemtype{{t={types}}}")]
+    EntityItemType {
+        /// A list of item types
+        types: String,
+    },
+    /// Tests the material of the target falling block or item
+    #[strum(to_string = "entitymaterialtype{{m={material}}}")]
+    EntityMaterialType {
+        /// The material to check
+        material: String,
+    },
+    /// Tests the type of the target entity
+    #[strum(to_string = "entitytype{{t={types}}}")]
+    EntityType {
+        /// A list of entity types or mythic mobs
+        types: String,
+    },
+    /// Tests the faction of the target
+    #[strum(to_string = "faction{{f={faction}}}")]
+    Faction {
+        /// The faction to check
+        faction: String,
+    },
+    /// Tests the fall speed of the target
+    #[strum(to_string = "fallspeed{{s={speed}}}")]
+    FallSpeed {
+        /// The fall speed
+        speed: NumberRange,
+    },
+    /// Whether the target is in the field of view of the caster
+    #[strum(to_string = "fieldofview{{a={angle};r={rotation}}}")]
+    FieldOfView {
+        /// The angle of the field of view, default 90
+        angle: f32,
+        /// The rotation of the field of view
+        rotation: f32,
+    },
+    /// Tests the food level of the target player
+    #[strum(to_string = "foodlevel{{a={amount}}}")]
+    FoodLevel {
+        /// The food level
+        amount: NumberRange,
+    },
+    /// Tests the food saturation of the target player
+    #[strum(to_string = "foodsaturation{{a={amount}}}")]
+    FoodSaturation {
+        /// The food saturation
+        amount: NumberRange,
+    },
+    /// Tests the gamemode of the target player
+    #[strum(to_string = "gamemode{{m={mode}}}")]
+    Gamemode {
+        /// A list of gamemodes: SURVIVAL, CREATIVE, ADVENTURE, SPECTATOR
+        mode: String,
+    },
+    /// Whether the target is gliding with an elytra
+    #[strum(to_string = "gliding")]
     Gliding,
-    GlobalScore,
+    /// Tests a global scoreboard value
+    #[strum(to_string = "globalscore{{o={objective};v={value}}}")]
+    GlobalScore {
+        /// The scoreboard objective
+        objective: String,
+        /// The score
+        value: NumberRange,
+    },
+    /// Whether the target has AI
+    #[strum(to_string = "hasai")]
     HasAI,
-    HasAura,
-    HasAuraStacks,
-    HasAuraType,
-    HasCurrency,
-    HasEnchantment,
+    /// Whether the target has the given aura
+    #[strum(to_string = "hasaura{{auraName={aura}}}")]
+    HasAura {
+        /// The name of the aura
+        aura: String,
+    },
+    /// Tests the stacks of an aura on the target
+    #[strum(to_string = "hasaurastacks{{auraName={aura};s={stacks}}}")]
+    HasAuraStacks {
+        /// The name of the aura
+        aura: String,
+        /// The amount of stacks
+        stacks: NumberRange,
+    },
+    /// Whether the target has an aura of the given type
+    #[strum(to_string = "hasauratype{{t={aura_type}}}")]
+    HasAuraType {
+        /// The type of the aura
+        aura_type: String,
+    },
+    /// Tests the money of the target player
+    #[strum(to_string = "hascurrency{{a={amount}}}")]
+    HasCurrency {
+        /// The amount of money
+        amount: NumberRange,
+    },
+    /// Whether the target holds or wears an item with the enchantment
+    #[strum(to_string = "hasenchantment{{e={enchantment};l={level}}}")]
+    HasEnchantment {
+        /// The enchantment
+        enchantment: String,
+        /// The level of the enchantment
+        level: NumberRange,
+    },
+    /// Whether the target player has a free inventory slot
+    #[strum(to_string = "hasfreeinventoryslot")]
     HasFreeInventorySlot,
+    /// Whether the target has gravity
+    #[strum(to_string = "hasgravity")]
     HasGravity,
-    HasItem,
+    /// Whether the target player has the given item
+    #[strum(to_string = "hasitem{{i={item};a={amount}}}")]
+    HasItem {
+        /// The item to look for
+        item: String,
+        /// The amount of items
+        amount: NumberRange,
+    },
+    /// Whether the target holds an item in its offhand
+    #[strum(to_string = "hasoffhand")]
     HasOffhand,
+    /// Whether the target has an owner
+    #[strum(to_string = "hasowner")]
     HasOwner,
+    /// Whether the target has a parent
+    #[strum(to_string = "hasparent")]
     HasParent,
+    /// Whether the target has a passenger
+    #[strum(to_string = "haspassenger")]
     HasPassenger,
-    HasPermission,
-    HasPotionEffect,
-    HasTag,
-    Health,
-    HealthPercent,
-    Height,
-    HeightAbove,
-    HeightBelow,
-    Holding,
+    /// Whether the target player has the permission
+    #[strum(to_string = "haspermission{{p={permission}}}")]
+    HasPermission {
+        /// The permission node
+        permission: String,
+    },
+    /// Whether the target has the potion effect
+    #[strum(to_string = "haspotioneffect{{t={effect};l={level};d={duration}}}")]
+    HasPotionEffect {
+        /// The potion effect type
+        effect: String,
+        /// The level of the effect
+        level: NumberRange,
+        /// The remaining duration in ticks
+        duration: NumberRange,
+    },
+    /// Whether the target has the scoreboard tag
+    #[strum(to_string = "hastag{{tag={tag}}}")]
+    HasTag {
+        /// The scoreboard tag
+        tag: String,
+    },
+    /// Tests the health of the target
+    #[strum(to_string = "health{{h={health}}}")]
+    Health {
+        /// The health range
+        health: NumberRange,
+    },
+    /// Tests the health of the target as a percentage of its max health
+    #[strum(to_string = "healthpercent{{p={percent}}}")]
+    HealthPercent {
+        /// The percentage, from 0 to 1
+        percent: NumberRange,
+    },
+    /// Tests the y coordinate of the target
+    #[strum(to_string = "height{{h={height}}}")]
+    Height {
+        /// The y coordinate
+        height: NumberRange,
+    },
+    /// Whether the y coordinate of the target is above the value
+    #[strum(to_string = "heightabove{{h={height}}}")]
+    HeightAbove {
+        /// The y coordinate
+        height: f32,
+    },
+    /// Whether the y coordinate of the target is below the value
+    #[strum(to_string = "heightbelow{{h={height}}}")]
+    HeightBelow {
+        /// The y coordinate
+        height: f32,
+    },
+    /// Whether the target holds the given material
+    #[strum(to_string = "holding{{m={material}}}")]
+    Holding {
+        /// A list of materials
+        material: String,
+    },
+    /// Whether the target is in a land claim
+    #[strum(to_string = "inclaim")]
     InClaim,
+    /// Whether the target is in combat
+    #[strum(to_string = "incombat")]
     InCombat,
-    InPinRegion,
+    /// Whether the target is in the region between two pins
+    #[strum(to_string = "inpinregion{{p1={pin_1};p2={pin_2}}}")]
+    InPinRegion {
+        /// The first corner pin
+        pin_1: String,
+        /// The second corner pin
+        pin_2: String,
+    },
+    /// Whether the target is invulnerable
+    #[strum(to_string = "isinvulnerable")]
     IsInvulnerable,
+    /// Whether the target player is in survival or adventure
+    #[strum(to_string = "isinsurvivalmode")]
     IsInSurvivalMode,
+    /// Whether the target has a block above it
+    #[strum(to_string = "inside")]
     Inside,
+    /// Whether the target is a baby
+    #[strum(to_string = "isbaby")]
     IsBaby,
+    /// Whether the target is the caster
+    #[strum(to_string = "iscaster")]
     IsCaster,
+    /// Whether the target is a child of the caster
+    #[strum(to_string = "ischild")]
     IsChild,
+    /// Whether the target is climbing
+    #[strum(to_string = "isclimbing")]
     IsClimbing,
+    /// Whether the target creeper is primed
+    #[strum(to_string = "iscreeperprimed")]
     IsCreeperPrimed,
+    /// Whether the target player is flying
+    #[strum(to_string = "isflying")]
     IsFlying,
+    /// Whether the target is frozen by powder snow
+    #[strum(to_string = "isfrozen")]
     IsFrozen,
+    /// Whether the target is leashed
+    #[strum(to_string = "isleashed")]
     IsLeashed,
+    /// Whether the target is a living entity
+    #[strum(to_string = "isliving")]
     IsLiving,
+    /// Whether the target is a monster
+    #[strum(to_string = "ismonster")]
     IsMonster,
+    /// Whether the target is a mythic mob
+    #[strum(to_string = "ismythicmob")]
     IsMythicMob,
+    /// Whether the parent of the caster is alive
+    #[strum(to_string = "isparentalive")]
     IsParentAlive,
+    /// Whether the target is the parent of the caster
+    #[strum(to_string = "isparent")]
     IsParent,
+    /// Whether the target is a player
+    #[strum(to_string = "isplayer")]
     IsPlayer,
+    /// Whether the target raider is a patrol leader
+    #[strum(to_string = "israiderpatrolleader")]
     IsRaiderPatrolLeader,
+    /// Whether the target is saddled
+    #[strum(to_string = "issaddled")]
     IsSaddled,
+    /// Whether the target is tamed
+    #[strum(to_string = "istamed")]
     IsTamed,
+    /// Whether the target player is using a spyglass
+    #[strum(to_string = "isusingspyglass")]
     IsUsingSpyglass,
-    ItemGroupOnCooldown,
-    ItemIsSimilar,
+    /// Whether the item group is on cooldown for the target player
+    #[strum(to_string = "itemgrouponcooldown{{g={group}}}")]
+    ItemGroupOnCooldown {
+        /// The item group
+        group: String,
+    },
+    /// Whether the item of the skill is similar to the given item
+    #[strum(to_string = "itemissimilar{{i={item}}}")]
+    ItemIsSimilar {
+        /// The item to compare to
+        item: String,
+    },
+    /// Whether the item of the skill is recharging
+    #[strum(to_string = "itemrecharging")]
     ItemRecharging,
-    ItemType,
-    LastDamageCause,
-    LastSignal,
-    Level,
-    LightLevel,
-    LightLevelFromBlocks,
+    /// Tests the type of the item of the skill
+    #[strum(to_string = "itemtype{{t={types}}}")]
+    ItemType {
+        /// A list of item types
+        types: String,
+    },
+    /// Tests the last damage cause of the target
+    #[strum(to_string = "lastdamagecause{{c={cause}}}")]
+    LastDamageCause {
+        /// A list of damage causes
+        cause: String,
+    },
+    /// Tests the last signal received by the target
+    #[strum(to_string = "lastsignal{{s={signal}}}")]
+    LastSignal {
+        /// The signal
+        signal: String,
+    },
+    /// Tests the level of the target mythic mob
+    #[strum(to_string = "level{{l={level}}}")]
+    Level {
+        /// The level
+        level: NumberRange,
+    },
+    /// Tests the light level at the target
+    #[strum(to_string = "lightlevel{{l={level}}}")]
+    LightLevel {
+        /// The light level
+        level: NumberRange,
+    },
+    /// Tests the light level from blocks at the target
+    #[strum(to_string = "lightlevelfromblocks{{l={level}}}")]
+    LightLevelFromBlocks {
+        /// The light level
+        level: NumberRange,
+    },
+    /// Whether the caster has line of sight to the target
+    #[strum(to_string = "lineofsight")]
     LineOfSight,
+    /// Whether the origin has line of sight to the target
+    #[strum(to_string = "lineofsightfromorigin")]
     LineOfSightFromOrigin,
-    LivingInRadius,
-    LocalDifficulty,
+    /// Tests the amount of living entities around the target
+    #[strum(to_string = "livinginradius{{a={amount};r={radius}}}")]
+    LivingInRadius {
+        /// The amount of entities
+        amount: NumberRange,
+        /// The radius to check in
+        radius: f32,
+    },
+    /// Tests the local difficulty at the target
+    #[strum(to_string = "localdifficulty{{d={difficulty}}}")]
+    LocalDifficulty {
+        /// The local difficulty
+        difficulty: NumberRange,
+    },
+    /// Whether the caster is looking at the target
+    #[strum(to_string = "lookingat")]
     LookingAt,
-    LunarPhase,
-    MaterialIsOnCooldown,
-    MobsInChunk,
-    MobsInRadius,
-    MobsInWorld,
-    MobsNearOrigin,
-    MobSize,
+    /// Tests the phase of the moon
+    #[strum(to_string = "lunarphase{{p={phase}}}")]
+    LunarPhase {
+        /// The moon phase, from 0 to 7
+        phase: u8,
+    },
+    /// Whether the material is on cooldown for the target player
+    #[strum(to_string = "materialisoncooldown{{m={material}}}")]
+    MaterialIsOnCooldown {
+        /// The material
+        material: String,
+    },
+    /// Tests the amount of mobs in the chunk of the target
+    #[strum(to_string = "mobsinchunk{{a={amount}}}")]
+    MobsInChunk {
+        /// The amount of mobs
+        amount: NumberRange,
+    },
+    /// Tests the amount of mobs of the given types around the target
+    #[strum(to_string = "mobsinradius{{t={types};a={amount};r={radius}}}")]
+    MobsInRadius {
+        /// A list of mob types
+        types: String,
+        /// The amount of mobs
+        amount: NumberRange,
+        /// The radius to check in
+        radius: f32,
+    },
+    /// Tests the amount of mobs in the world of the target
+    #[strum(to_string = "mobsinworld{{a={amount}}}")]
+    MobsInWorld {
+        /// The amount of mobs
+        amount: NumberRange,
+    },
+    /// Tests the amount of mobs of the given types around the origin
+    #[strum(to_string = "mobsnearorigin{{t={types};a={amount};r={radius}}}")]
+    MobsNearOrigin {
+        /// A list of mob types
+        types: String,
+        /// The amount of mobs
+        amount: NumberRange,
+        /// The radius to check in
+        radius: f32,
+    },
+    /// Tests the size of the target slime or phantom
+    #[strum(to_string = "mobsize{{s={size}}}")]
+    MobSize {
+        /// The size
+        size: NumberRange,
+    },
+    /// Whether the target farmland is moist
+    #[strum(to_string = "moist")]
     Moist,
-    MoistureLevel,
-    MotionX,
-    MotionY,
-    MotionZ,
+    /// Tests the moisture level of the target farmland
+    #[strum(to_string = "moisturelevel{{l={level}}}")]
+    MoistureLevel {
+        /// The moisture level
+        level: NumberRange,
+    },
+    /// Tests the velocity of the target on the x axis
+    #[strum(to_string = "motionx{{v={velocity}}}")]
+    MotionX {
+        /// The velocity
+        velocity: NumberRange,
+    },
+    /// Tests the velocity of the target on the y axis
+    #[strum(to_string = "motiony{{v={velocity}}}")]
+    MotionY {
+        /// The velocity
+        velocity: NumberRange,
+    },
+    /// Tests the velocity of the target on the z axis
+    #[strum(to_string = "motionz{{v={velocity}}}")]
+    MotionZ {
+        /// The velocity
+        velocity: NumberRange,
+    },
+    /// Whether the target is riding an entity
+    #[strum(to_string = "mounted")]
     Mounted,
+    /// Whether the target is moving
+    #[strum(to_string = "moving")]
     Moving,
-    MythicMobType,
-    MythicPack,
-    MythicPackVersion,
-    MythicPackVersionGreater,
-    Name,
-    NearClaim,
+    /// Tests the internal name of the target mythic mob
+    #[strum(to_string = "mythicmobtype{{t={types}}}")]
+    MythicMobType {
+        /// A list of mob internal names
+        types: String,
+    },
+    /// Whether the pack is installed
+    #[strum(to_string = "mythicpack{{p={pack}}}")]
+    MythicPack {
+        /// The name of the pack
+        pack: String,
+    },
+    /// Tests the version of an installed pack
+    #[strum(to_string = "mythicpackversion{{p={pack};v={version}}}")]
+    MythicPackVersion {
+        /// The name of the pack
+        pack: String,
+        /// The version
+        version: String,
+    },
+    /// Whether the version of an installed pack is greater than the given one
+    #[strum(to_string = "mythicpackversiongreater{{p={pack};v={version}}}")]
+    MythicPackVersionGreater {
+        /// The name of the pack
+        pack: String,
+        /// The version
+        version: String,
+    },
+    /// Tests the name of the target
+    #[strum(to_string = "name{{n={name}}}")]
+    Name {
+        /// The name
+        name: String,
+    },
+    /// Whether a land claim is near the target
+    #[strum(to_string = "nearclaim{{r={radius}}}")]
+    NearClaim {
+        /// The radius to check in
+        radius: f32,
+    },
+    /// Whether it is night, from 14000 to 22000 ticks
+    #[strum(to_string = "night")]
     Night,
-    NotInRegion,
+    /// Whether the target is outside the WorldGuard regions
+    #[strum(to_string = "notinregion{{r={region}}}")]
+    NotInRegion {
+        /// A list of regions
+        region: String,
+    },
+    /// Whether the caster is off its global cooldown
+    #[strum(to_string = "offgcd")]
     OffGCD,
-    OnBlock,
+    /// Whether the target stands on the given blocks
+    #[strum(to_string = "onblock{{b={blocks}}}")]
+    OnBlock {
+        /// A list of materials
+        blocks: String,
+    },
+    /// Whether the target is on the ground
+    #[strum(to_string = "onground")]
     OnGround,
-    OriginDistanceFromPin,
-    OriginLocation,
+    /// Tests the distance between the origin and a pin
+    #[strum(to_string = "origindistancefrompin{{p={pin};d={distance}}}")]
+    OriginDistanceFromPin {
+        /// The name of the pin
+        pin: String,
+        /// The distance range
+        distance: NumberRange,
+    },
+    /// Whether the origin is at the location
+    #[strum(to_string = "originlocation{{l={location}}}")]
+    OriginLocation {
+        /// The location, as x,y,z
+        location: String,
+    },
+    /// Whether the target has no block above it
+    #[strum(to_string = "outside")]
     Outside,
+    /// Whether the target is the owner of the caster
+    #[strum(to_string = "owner")]
     Owner,
+    /// Whether the owner of the caster is online
+    #[strum(to_string = "ownerisonline")]
     OwnerIsOnline,
-    Pitch,
-    PlayerKills,
-    PlayerNotWithin,
-    PlayerWithin,
-    PlayersInRadius,
-    PlayersInWorld,
-    PlayersOnline,
-    Plugin,
+    /// Tests the pitch of the target
+    #[strum(to_string = "pitch{{p={pitch}}}")]
+    Pitch {
+        /// The pitch
+        pitch: NumberRange,
+    },
+    /// Tests the amount of players the target mythic mob has killed
+    #[strum(to_string = "playerkills{{k={kills}}}")]
+    PlayerKills {
+        /// The amount of kills
+        kills: NumberRange,
+    },
+    /// Whether no player is within the distance of the target
+    #[strum(to_string = "playernotwithin{{d={distance}}}")]
+    PlayerNotWithin {
+        /// The distance
+        distance: f32,
+    },
+    /// Whether a player is within the distance of the target
+    #[strum(to_string = "playerwithin{{d={distance}}}")]
+    PlayerWithin {
+        /// The distance
+        distance: f32,
+    },
+    /// Tests the amount of players around the target
+    #[strum(to_string = "playersinradius{{a={amount};r={radius}}}")]
+    PlayersInRadius {
+        /// The amount of players
+        amount: NumberRange,
+        /// The radius to check in
+        radius: f32,
+    },
+    /// Tests the amount of players in the world of the target
+    #[strum(to_string = "playersinworld{{a={amount}}}")]
+    PlayersInWorld {
+        /// The amount of players
+        amount: NumberRange,
+    },
+    /// Tests the amount of players online
+    #[strum(to_string = "playersonline{{a={amount}}}")]
+    PlayersOnline {
+        /// The amount of players
+        amount: NumberRange,
+    },
+    /// Whether the plugin is enabled
+    #[strum(to_string = "plugin{{p={plugin}}}")]
+    Plugin {
+        /// The name of the plugin
+        plugin: String,
+    },
+    /// Whether the server runs MythicMobs Premium
+    #[strum(to_string = "premium")]
     Premium,
+    /// Whether it is raining at the target
+    #[strum(to_string = "raining")]
     Raining,
-    Region,
+    /// Whether the target is inside the WorldGuard regions
+    #[strum(to_string = "region{{r={region}}}")]
+    Region {
+        /// A list of regions
+        region: String,
+    },
+    /// Whether the target is in the faction of the caster
+    #[strum(to_string = "samefaction")]
     SameFaction,
-    Score,
+    /// Tests the scoreboard value of the target
+    #[strum(to_string = "score{{o={objective};v={value}}}")]
+    Score {
+        /// The scoreboard objective
+        objective: String,
+        /// The score
+        value: NumberRange,
+    },
+    /// Whether the server runs Paper
+    #[strum(to_string = "serverispaper")]
     ServerIsPaper,
-    HasScoreboardTag,
+    /// Whether the target has the scoreboard tag
+    #[strum(to_string = "hasscoreboardtag{{tag={tag}}}")]
+    HasScoreboardTag {
+        /// The scoreboard tag
+        tag: String,
+    },
+    /// Whether the target sheep is sheared
+    #[strum(to_string = "sheared")]
     Sheared,
+    /// Whether the target blocks the attack with a shield
+    #[strum(to_string = "shieldblocking")]
     ShieldBlocking,
+    /// Whether the target is sitting
+    #[strum(to_string = "sitting")]
     Sitting,
-    SkillLevel,
-    SkillTrigger,
-    Slot,
-    SlotType,
+    /// Tests the level of the skill
+    #[strum(to_string = "skilllevel{{l={level}}}")]
+    SkillLevel {
+        /// The level
+        level: NumberRange,
+    },
+    /// Tests the trigger that cast the skill
+    #[strum(to_string = "skilltrigger{{t={trigger}}}")]
+    SkillTrigger {
+        /// The trigger, ex: onDamaged
+        trigger: String,
+    },
+    /// Tests the item in an equipment slot of the target
+    #[strum(to_string = "slot{{s={slot};i={item}}}")]
+    Slot {
+        /// The slot, ex: HAND, HEAD
+        slot: String,
+        /// The item
+        item: String,
+    },
+    /// Tests the slot of the item of the skill
+    #[strum(to_string = "slottype{{s={slot}}}")]
+    SlotType {
+        /// The slot type
+        slot: String,
+    },
+    /// Whether the target is sneaking
+    #[strum(to_string = "sneaking")]
     Sneaking,
+    /// Whether it is snowing at the target
+    #[strum(to_string = "snowing")]
     Snowing,
-    Sphere,
+    /// Whether the target is inside the sphere
+    #[strum(to_string = "sphere{{c={center};r={radius}}}")]
+    Sphere {
+        /// The center of the sphere, as x,y,z
+        center: String,
+        /// The radius of the sphere
+        radius: f32,
+    },
+    /// Whether the target is sprinting
+    #[strum(to_string = "sprinting")]
     Sprinting,
+    /// Whether it is storming at the target
+    #[strum(to_string = "storming")]
     Storming,
-    StringEquals,
-    StringEqualsIgnoreCase,
-    StringLength,
-    StringMatches,
-    TargetLocation,
+    /// Whether the two strings are equal
+    #[strum(to_string = "stringequals{{v1={value_1};v2={value_2}}}")]
+    StringEquals {
+        /// The first string
+        value_1: String,
+        /// The second string
+        value_2: String,
+    },
+    /// Whether the two strings are equal, ignoring the case
+    #[strum(to_string = "stringequalsignorecase{{v1={value_1};v2={value_2}}}")]
+    StringEqualsIgnoreCase {
+        /// The first string
+        value_1: String,
+        /// The second string
+        value_2: String,
+    },
+    /// Tests the length of the string
+    #[strum(to_string = "stringlength{{v={value};l={length}}}")]
+    StringLength {
+        /// The string
+        value: String,
+        /// The length
+        length: NumberRange,
+    },
+    /// Whether the string matches the regular expression
+    #[strum(to_string = "stringmatches{{v={value};r={regex}}}")]
+    StringMatches {
+        /// The string
+        value: String,
+        /// The regular expression
+        regex: String,
+    },
+    /// Whether the target is at the location
+    #[strum(to_string = "targetlocation{{l={location}}}")]
+    TargetLocation {
+        /// The location, as x,y,z
+        location: String,
+    },
+    /// Whether it is thundering at the target
+    #[strum(to_string = "thundering")]
     Thundering,
-    Time,
-    TimeInRadius,
+    /// Tests the time of the world of the target
+    #[strum(to_string = "time{{t={time}}}")]
+    Time {
+        /// The time in ticks
+        time: NumberRange,
+    },
+    /// Tests how long the target has been around the caster
+    #[strum(to_string = "timeinradius{{r={radius};t={time}}}")]
+    TimeInRadius {
+        /// The radius to check in
+        radius: f32,
+        /// The time in ticks
+        time: NumberRange,
+    },
+    /// Whether the target is at the tracked location of the caster
+    #[strum(to_string = "trackedlocation")]
     TrackedLocation,
-    Velocity,
+    /// Tests the velocity of the target
+    #[strum(to_string = "velocity{{v={velocity}}}")]
+    Velocity {
+        /// The velocity
+        velocity: NumberRange,
+    },
+    /// Whether the target is in water or rain
+    #[strum(to_string = "wet")]
     Wet,
-    World,
-    WorldDifficulty,
-    WorldTime,
-    Yaw,
+    /// Tests the world the target is in
+    #[strum(to_string = "world{{w={world}}}")]
+    World {
+        /// A list of worlds
+        world: String,
+    },
+    /// Tests the difficulty of the world
+    #[strum(to_string = "worlddifficulty{{d={difficulty}}}")]
+    WorldDifficulty {
+        /// PEACEFUL, EASY, NORMAL or HARD
+        difficulty: String,
+    },
+    /// Tests the full time of the world of the target
+    #[strum(to_string = "worldtime{{t={time}}}")]
+    WorldTime {
+        /// The time in ticks
+        time: NumberRange,
+    },
+    /// Tests the yaw of the target
+    #[strum(to_string = "yaw{{y={yaw}}}")]
+    Yaw {
+        /// The yaw
+        yaw: NumberRange,
+    },
+}
+
+impl Condition {
+    /// The condition with the defaults documented on its fields, `Condition::iter()` zeroes every field.
+    /// Conditions without a documented default keep the zeroed fields for the user to fill in
+    pub fn with_defaults(self) -> Self {
+        match self {
+            Condition::Altitude { height, .. } => Condition::Altitude {
+                height,
+                max_height: 30,
+            },
+            Condition::Chance { .. } => Condition::Chance { chance: 0.5 },
+            Condition::FieldOfView { rotation, .. } => Condition::FieldOfView {
+                angle: 90.0,
+                rotation,
+            },
+            condition => condition,
+        }
+    }
+
+    /// The condition as written in a config, without its empty parameters
+    pub fn config(&self) -> String {
+        omit_empty_args(&self.to_string())
+    }
+
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            Condition::Altitude { .. } => "Altitude",
+            Condition::Biome { .. } => "Biome",
+            Condition::BiomeType { .. } => "Biome Type",
+            Condition::BlockType { .. } => "Block Type",
+            Condition::BlockTypeInRadius { .. } => "Block Type In Radius",
+            Condition::Blocking => "Blocking",
+            Condition::BoundingBoxesOverlap => "Bounding Boxes Overlap",
+            Condition::BowTension { .. } => "Bow Tension",
+            Condition::Burning => "Burning",
+            Condition::Chance { .. } => "Chance",
+            Condition::Charged => "Charged",
+            Condition::Children { .. } => "Children",
+            Condition::Color { .. } => "Color",
+            Condition::CompareValues { .. } => "Compare Values",
+            Condition::Crouching => "Crouching",
+            Condition::Cuboid { .. } => "Cuboid",
+            Condition::DamageAmount { .. } => "Damage Amount",
+            Condition::DamageCause { .. } => "Damage Cause",
+            Condition::DamageTag { .. } => "Damage Tag",
+            Condition::Dawn => "Dawn",
+            Condition::Day => "Day",
+            Condition::Dimension { .. } => "Dimension",
+            Condition::DirectionalVelocity { .. } => "Directional Velocity",
+            Condition::Distance { .. } => "Distance",
+            Condition::DistanceFromLocation { .. } => "Distance From Location",
+            Condition::DistanceFromPin { .. } => "Distance From Pin",
+            Condition::DistanceFromSpawn { .. } => "Distance From Spawn",
+            Condition::DistanceFromTrackedLocation { .. } => "Distance From Tracked Location",
+            Condition::Dusk => "Dusk",
+            Condition::EnchantingExperience { .. } => "Enchanting Experience",
+            Condition::EnchantingLevel { .. } => "Enchanting Level",
+            Condition::EnderDragonAlive => "Ender Dragon Alive",
+            Condition::EnderDragonPhase { .. } => "Ender Dragon Phase",
+            Condition::EntityItemIsSimilar { .. } => "Entity Item Is Similar",
+            Condition::EntityItemType { .. } => "Entity Item Type",
+            Condition::EntityMaterialType { .. } => "Entity Material Type",
+            Condition::EntityType { .. } => "Entity Type",
+            Condition::Faction { .. } => "Faction",
+            Condition::FallSpeed { .. } => "Fall Speed",
+            Condition::FieldOfView { .. } => "Field Of View",
+            Condition::FoodLevel { .. } => "Food Level",
+            Condition::FoodSaturation { .. } => "Food Saturation",
+            Condition::Gamemode { .. } => "Gamemode",
+            Condition::Gliding => "Gliding",
+            Condition::GlobalScore { .. } => "Global Score",
+            Condition::HasAI => "Has AI",
+            Condition::HasAura { .. } => "Has Aura",
+            Condition::HasAuraStacks { .. } => "Has Aura Stacks",
+            Condition::HasAuraType { .. } => "Has Aura Type",
+            Condition::HasCurrency { .. } => "Has Currency",
+            Condition::HasEnchantment { .. } => "Has Enchantment",
+            Condition::HasFreeInventorySlot => "Has Free Inventory Slot",
+            Condition::HasGravity => "Has Gravity",
+            Condition::HasItem { .. } => "Has Item",
+            Condition::HasOffhand => "Has Offhand",
+            Condition::HasOwner => "Has Owner",
+            Condition::HasParent => "Has Parent",
+            Condition::HasPassenger => "Has Passenger",
+            Condition::HasPermission { .. } => "Has Permission",
+            Condition::HasPotionEffect { .. } => "Has Potion Effect",
+            Condition::HasTag { .. } => "Has Tag",
+            Condition::Health { .. } => "Health",
+            Condition::HealthPercent { .. } => "Health Percent",
+            Condition::Height { .. } => "Height",
+            Condition::HeightAbove { .. } => "Height Above",
+            Condition::HeightBelow { .. } => "Height Below",
+            Condition::Holding { .. } => "Holding",
+            Condition::InClaim => "In Claim",
+            Condition::InCombat => "In Combat",
+            Condition::InPinRegion { .. } => "In Pin Region",
+            Condition::IsInvulnerable => "Is Invulnerable",
+            Condition::IsInSurvivalMode => "Is In Survival Mode",
+            Condition::Inside => "Inside",
+            Condition::IsBaby => "Is Baby",
+            Condition::IsCaster => "Is Caster",
+            Condition::IsChild => "Is Child",
+            Condition::IsClimbing => "Is Climbing",
+            Condition::IsCreeperPrimed => "Is Creeper Primed",
+            Condition::IsFlying => "Is Flying",
+            Condition::IsFrozen => "Is Frozen",
+            Condition::IsLeashed => "Is Leashed",
+            Condition::IsLiving => "Is Living",
+            Condition::IsMonster => "Is Monster",
+            Condition::IsMythicMob => "Is Mythic Mob",
+            Condition::IsParentAlive => "Is Parent Alive",
+            Condition::IsParent => "Is Parent",
+            Condition::IsPlayer => "Is Player",
+            Condition::IsRaiderPatrolLeader => "Is Raider Patrol Leader",
+            Condition::IsSaddled => "Is Saddled",
+            Condition::IsTamed => "Is Tamed",
+            Condition::IsUsingSpyglass => "Is Using Spyglass",
+            Condition::ItemGroupOnCooldown { .. } => "Item Group On Cooldown",
+            Condition::ItemIsSimilar { .. } => "Item Is Similar",
+            Condition::ItemRecharging => "Item Recharging",
+            Condition::ItemType { .. } => "Item Type",
+            Condition::LastDamageCause { .. } => "Last Damage Cause",
+            Condition::LastSignal { .. } => "Last Signal",
+            Condition::Level { .. } => "Level",
+            Condition::LightLevel { .. } => "Light Level",
+            Condition::LightLevelFromBlocks { .. } => "Light Level From Blocks",
+            Condition::LineOfSight => "Line Of Sight",
+            Condition::LineOfSightFromOrigin => "Line Of Sight From Origin",
+            Condition::LivingInRadius { .. } => "Living In Radius",
+            Condition::LocalDifficulty { .. } => "Local Difficulty",
+            Condition::LookingAt => "Looking At",
+            Condition::LunarPhase { .. } => "Lunar Phase",
+            Condition::MaterialIsOnCooldown { .. } => "Material Is On Cooldown",
+            Condition::MobsInChunk { .. } => "Mobs In Chunk",
+            Condition::MobsInRadius { .. } => "Mobs In Radius",
+            Condition::MobsInWorld { .. } => "Mobs In World",
+            Condition::MobsNearOrigin { .. } => "Mobs Near Origin",
+            Condition::MobSize { .. } => "Mob Size",
+            Condition::Moist => "Moist",
+            Condition::MoistureLevel { .. } => "Moisture Level",
+            Condition::MotionX { .. } => "Motion X",
+            Condition::MotionY { .. } => "Motion Y",
+            Condition::MotionZ { .. } => "Motion Z",
+            Condition::Mounted => "Mounted",
+            Condition::Moving => "Moving",
+            Condition::MythicMobType { .. } => "Mythic Mob Type",
+            Condition::MythicPack { .. } => "Mythic Pack",
+            Condition::MythicPackVersion { .. } => "Mythic Pack Version",
+            Condition::MythicPackVersionGreater { .. } => "Mythic Pack Version Greater",
+            Condition::Name { .. } => "Name",
+            Condition::NearClaim { .. } => "Near Claim",
+            Condition::Night => "Night",
+            Condition::NotInRegion { .. } => "Not In Region",
+            Condition::OffGCD => "Off GCD",
+            Condition::OnBlock { .. } => "On Block",
+            Condition::OnGround => "On Ground",
+            Condition::OriginDistanceFromPin { .. } => "Origin Distance From Pin",
+            Condition::OriginLocation { .. } => "Origin Location",
+            Condition::Outside => "Outside",
+            Condition::Owner => "Owner",
+            Condition::OwnerIsOnline => "Owner Is Online",
+            Condition::Pitch { .. } => "Pitch",
+            Condition::PlayerKills { .. } => "Player Kills",
+            Condition::PlayerNotWithin { .. } => "Player Not Within",
+            Condition::PlayerWithin { .. } => "Player Within",
+            Condition::PlayersInRadius { .. } => "Players In Radius",
+            Condition::PlayersInWorld { .. } => "Players In World",
+            Condition::PlayersOnline { .. } => "Players Online",
+            Condition::Plugin { .. } => "Plugin",
+            Condition::Premium => "Premium",
+            Condition::Raining => "Raining",
+            Condition::Region { .. } => "Region",
+            Condition::SameFaction => "Same Faction",
+            Condition::Score { .. } => "Score",
+            Condition::ServerIsPaper => "Server Is Paper",
+            Condition::HasScoreboardTag { .. } => "Has Scoreboard Tag",
+            Condition::Sheared => "Sheared",
+            Condition::ShieldBlocking => "Shield Blocking",
+            Condition::Sitting => "Sitting",
+            Condition::SkillLevel { .. } => "Skill Level",
+            Condition::SkillTrigger { .. } => "Skill Trigger",
+            Condition::Slot { .. } => "Slot",
+            Condition::SlotType { .. } => "Slot Type",
+            Condition::Sneaking => "Sneaking",
+            Condition::Snowing => "Snowing",
+            Condition::Sphere { .. } => "Sphere",
+            Condition::Sprinting => "Sprinting",
+            Condition::Storming => "Storming",
+            Condition::StringEquals { .. } => "String Equals",
+            Condition::StringEqualsIgnoreCase { .. } => "String Equals Ignore Case",
+            Condition::StringLength { .. } => "String Length",
+            Condition::StringMatches { .. } => "String Matches",
+            Condition::TargetLocation { .. } => "Target Location",
+            Condition::Thundering => "Thundering",
+            Condition::Time { .. } => "Time",
+            Condition::TimeInRadius { .. } => "Time In Radius",
+            Condition::TrackedLocation => "Tracked Location",
+            Condition::Velocity { .. } => "Velocity",
+            Condition::Wet => "Wet",
+            Condition::World { .. } => "World",
+            Condition::WorldDifficulty { .. } => "World Difficulty",
+            Condition::WorldTime { .. } => "World Time",
+            Condition::Yaw { .. } => "Yaw",
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash) {
+        let id = egui::Id::new(id_salt);
+        egui::ComboBox::new(("condition", id), "")
+            .selected_text(self.get_fields().into())
+            .show_ui(ui, |ui| {
+                Condition::iter().for_each(|v| {
+                    let selected = std::mem::discriminant(self) == std::mem::discriminant(&v);
+                    if ui
+                        .selectable_label(selected, v.get_fields().into())
+                        .clicked()
+                        && !selected
+                    {
+                        *self = v.with_defaults();
+                    }
+                });
+            });
+        match self {
+            Condition::Altitude { height, max_height } => {
+                param_label(ui, "Height", "The height range to check");
+                height.handle_ui(ui, id.with("height"));
+                param_label(
+                    ui,
+                    "Max Height",
+                    "Limits the maximum height this condition can checks for, default 30",
+                );
+                ui.add(egui::DragValue::new(max_height));
+            }
+            Condition::Biome { biome, exact } => {
+                param_label(ui, "Biome", "A list of biomes to check");
+                biome.handle_ui(ui, id.with("biome"));
+                ui.checkbox(exact, "Exact").on_hover_ui(|ui| {
+                    ui.label("Whether to match the biome exactly");
+                });
+            }
+            Condition::BiomeType { biome_type, exact } => {
+                param_label(
+                    ui,
+                    "Biome Type",
+                    "A list of biome types to check: none, taiga, extreme_hills, jungle, mesa, plains, savanna, icy, the_end, beach, forest, ocean, desert, river, swamp, mushroom, nether, underground, mountain",
+                );
+                ui.text_edit_singleline(biome_type);
+                ui.checkbox(exact, "Exact").on_hover_ui(|ui| {
+                    ui.label("Whether to match the type exactly");
+                });
+            }
+            Condition::BlockType { types } => {
+                param_label(ui, "Types", "A list of materials to check");
+                ui.text_edit_singleline(types);
+            }
+            Condition::BlockTypeInRadius {
+                types,
+                radius,
+                amount,
+            } => {
+                param_label(ui, "Types", "A list of materials to check");
+                ui.text_edit_singleline(types);
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+                param_label(ui, "Amount", "The amount of blocks to match");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::BowTension { tension } => {
+                param_label(ui, "Tension", "The tension range, from 0 to 1");
+                tension.handle_ui(ui, id.with("tension"));
+            }
+            Condition::Chance { chance } => {
+                param_label(ui, "Chance", "The chance to pass, from 0 to 1");
+                ui.add(egui::DragValue::new(chance).speed(0.1));
+            }
+            Condition::Children { amount } => {
+                param_label(ui, "Amount", "The amount of children");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::Color { color } => {
+                param_label(ui, "Color", "The color to check");
+                ui.text_edit_singleline(color);
+            }
+            Condition::CompareValues { value } => {
+                param_label(ui, "Value", "The comparison to evaluate");
+                ui.text_edit_singleline(value);
+            }
+            Condition::Cuboid { point_a, point_b } => {
+                param_label(ui, "Point A", "The first corner, as x,y,z");
+                ui.text_edit_singleline(point_a);
+                param_label(ui, "Point B", "The second corner, as x,y,z");
+                ui.text_edit_singleline(point_b);
+            }
+            Condition::DamageAmount { amount } => {
+                param_label(ui, "Amount", "The amount of damage");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::DamageCause { cause } => {
+                param_label(ui, "Cause", "The damage cause, ex: ENTITY_ATTACK");
+                ui.text_edit_singleline(cause);
+            }
+            Condition::DamageTag { tag } => {
+                param_label(ui, "Tag", "The damage tag");
+                ui.text_edit_singleline(tag);
+            }
+            Condition::Dimension { dimension } => {
+                param_label(ui, "Dimension", "NORMAL, NETHER or THE_END");
+                ui.text_edit_singleline(dimension);
+            }
+            Condition::DirectionalVelocity { x, y, z } => {
+                param_label(ui, "X", "The velocity on the x axis");
+                x.handle_ui(ui, id.with("x"));
+                param_label(ui, "Y", "The velocity on the y axis");
+                y.handle_ui(ui, id.with("y"));
+                param_label(ui, "Z", "The velocity on the z axis");
+                z.handle_ui(ui, id.with("z"));
+            }
+            Condition::Distance { distance } => {
+                param_label(ui, "Distance", "The distance range");
+                distance.handle_ui(ui, id.with("distance"));
+            }
+            Condition::DistanceFromLocation {
+                world,
+                location,
+                distance,
+            } => {
+                param_label(ui, "World", "The world of the location");
+                ui.text_edit_singleline(world);
+                param_label(ui, "Location", "The location, as x,y,z");
+                ui.text_edit_singleline(location);
+                param_label(ui, "Distance", "The distance range");
+                distance.handle_ui(ui, id.with("distance"));
+            }
+            Condition::DistanceFromPin { pin, distance } => {
+                param_label(ui, "Pin", "The name of the pin");
+                ui.text_edit_singleline(pin);
+                param_label(ui, "Distance", "The distance range");
+                distance.handle_ui(ui, id.with("distance"));
+            }
+            Condition::DistanceFromSpawn { distance } => {
+                param_label(ui, "Distance", "The distance range");
+                distance.handle_ui(ui, id.with("distance"));
+            }
+            Condition::DistanceFromTrackedLocation { distance } => {
+                param_label(ui, "Distance", "The distance range");
+                distance.handle_ui(ui, id.with("distance"));
+            }
+            Condition::EnchantingExperience { amount } => {
+                param_label(ui, "Amount", "The amount of experience");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::EnchantingLevel { level } => {
+                param_label(ui, "Level", "The experience level");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::EnderDragonPhase { phase } => {
+                param_label(ui, "Phase", "A list of phases, ex: CIRCLING,STRAFING");
+                ui.text_edit_singleline(phase);
+            }
+            Condition::EntityItemIsSimilar { item } => {
+                param_label(ui, "Item", "The item to compare to");
+                ui.text_edit_singleline(item);
+            }
+            Condition::EntityItemType { types } => {
+                param_label(ui, "Types", "A list of item types");
+                ui.text_edit_singleline(types);
+            }
+            Condition::EntityMaterialType { material } => {
+                param_label(ui, "Material", "The material to check");
+                ui.text_edit_singleline(material);
+            }
+            Condition::EntityType { types } => {
+                param_label(ui, "Types", "A list of entity types or mythic mobs");
+                ui.text_edit_singleline(types);
+            }
+            Condition::Faction { faction } => {
+                param_label(ui, "Faction", "The faction to check");
+                ui.text_edit_singleline(faction);
+            }
+            Condition::FallSpeed { speed } => {
+                param_label(ui, "Speed", "The fall speed");
+                speed.handle_ui(ui, id.with("speed"));
+            }
+            Condition::FieldOfView { angle, rotation } => {
+                param_label(ui, "Angle", "The angle of the field of view");
+                ui.add(egui::DragValue::new(angle).speed(0.1));
+                param_label(ui, "Rotation", "The rotation of the field of view");
+                ui.add(egui::DragValue::new(rotation).speed(0.1));
+            }
+            Condition::FoodLevel { amount } => {
+                param_label(ui, "Amount", "The food level");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::FoodSaturation { amount } => {
+                param_label(ui, "Amount", "The food saturation");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::Gamemode { mode } => {
+                param_label(
+                    ui,
+                    "Mode",
+                    "A list of gamemodes: SURVIVAL, CREATIVE, ADVENTURE, SPECTATOR",
+                );
+                ui.text_edit_singleline(mode);
+            }
+            Condition::GlobalScore { objective, value } => {
+                param_label(ui, "Objective", "The scoreboard objective");
+                ui.text_edit_singleline(objective);
+                param_label(ui, "Value", "The score");
+                value.handle_ui(ui, id.with("value"));
+            }
+            Condition::HasAura { aura } => {
+                param_label(ui, "Aura", "The name of the aura");
+                ui.text_edit_singleline(aura);
+            }
+            Condition::HasAuraStacks { aura, stacks } => {
+                param_label(ui, "Aura", "The name of the aura");
+                ui.text_edit_singleline(aura);
+                param_label(ui, "Stacks", "The amount of stacks");
+                stacks.handle_ui(ui, id.with("stacks"));
+            }
+            Condition::HasAuraType { aura_type } => {
+                param_label(ui, "Aura Type", "The type of the aura");
+                ui.text_edit_singleline(aura_type);
+            }
+            Condition::HasCurrency { amount } => {
+                param_label(ui, "Amount", "The amount of money");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::HasEnchantment { enchantment, level } => {
+                param_label(ui, "Enchantment", "The enchantment");
+                ui.text_edit_singleline(enchantment);
+                param_label(ui, "Level", "The level of the enchantment");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::HasItem { item, amount } => {
+                param_label(ui, "Item", "The item to look for");
+                ui.text_edit_singleline(item);
+                param_label(ui, "Amount", "The amount of items");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::HasPermission { permission } => {
+                param_label(ui, "Permission", "The permission node");
+                ui.text_edit_singleline(permission);
+            }
+            Condition::HasPotionEffect {
+                effect,
+                level,
+                duration,
+            } => {
+                param_label(ui, "Effect", "The potion effect type");
+                ui.text_edit_singleline(effect);
+                param_label(ui, "Level", "The level of the effect");
+                level.handle_ui(ui, id.with("level"));
+                param_label(ui, "Duration", "The remaining duration in ticks");
+                duration.handle_ui(ui, id.with("duration"));
+            }
+            Condition::HasTag { tag } => {
+                param_label(ui, "Tag", "The scoreboard tag");
+                ui.text_edit_singleline(tag);
+            }
+            Condition::Health { health } => {
+                param_label(ui, "Health", "The health range");
+                health.handle_ui(ui, id.with("health"));
+            }
+            Condition::HealthPercent { percent } => {
+                param_label(ui, "Percent", "The percentage, from 0 to 1");
+                percent.handle_ui(ui, id.with("percent"));
+            }
+            Condition::Height { height } => {
+                param_label(ui, "Height", "The y coordinate");
+                height.handle_ui(ui, id.with("height"));
+            }
+            Condition::HeightAbove { height } => {
+                param_label(ui, "Height", "The y coordinate");
+                ui.add(egui::DragValue::new(height).speed(0.1));
+            }
+            Condition::HeightBelow { height } => {
+                param_label(ui, "Height", "The y coordinate");
+                ui.add(egui::DragValue::new(height).speed(0.1));
+            }
+            Condition::Holding { material } => {
+                param_label(ui, "Material", "A list of materials");
+                ui.text_edit_singleline(material);
+            }
+            Condition::InPinRegion { pin_1, pin_2 } => {
+                param_label(ui, "Pin 1", "The first corner pin");
+                ui.text_edit_singleline(pin_1);
+                param_label(ui, "Pin 2", "The second corner pin");
+                ui.text_edit_singleline(pin_2);
+            }
+            Condition::ItemGroupOnCooldown { group } => {
+                param_label(ui, "Group", "The item group");
+                ui.text_edit_singleline(group);
+            }
+            Condition::ItemIsSimilar { item } => {
+                param_label(ui, "Item", "The item to compare to");
+                ui.text_edit_singleline(item);
+            }
+            Condition::ItemType { types } => {
+                param_label(ui, "Types", "A list of item types");
+                ui.text_edit_singleline(types);
+            }
+            Condition::LastDamageCause { cause } => {
+                param_label(ui, "Cause", "A list of damage causes");
+                ui.text_edit_singleline(cause);
+            }
+            Condition::LastSignal { signal } => {
+                param_label(ui, "Signal", "The signal");
+                ui.text_edit_singleline(signal);
+            }
+            Condition::Level { level } => {
+                param_label(ui, "Level", "The level");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::LightLevel { level } => {
+                param_label(ui, "Level", "The light level");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::LightLevelFromBlocks { level } => {
+                param_label(ui, "Level", "The light level");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::LivingInRadius { amount, radius } => {
+                param_label(ui, "Amount", "The amount of entities");
+                amount.handle_ui(ui, id.with("amount"));
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            Condition::LocalDifficulty { difficulty } => {
+                param_label(ui, "Difficulty", "The local difficulty");
+                difficulty.handle_ui(ui, id.with("difficulty"));
+            }
+            Condition::LunarPhase { phase } => {
+                param_label(ui, "Phase", "The moon phase, from 0 to 7");
+                ui.add(egui::DragValue::new(phase));
+            }
+            Condition::MaterialIsOnCooldown { material } => {
+                param_label(ui, "Material", "The material");
+                ui.text_edit_singleline(material);
+            }
+            Condition::MobsInChunk { amount } => {
+                param_label(ui, "Amount", "The amount of mobs");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::MobsInRadius {
+                types,
+                amount,
+                radius,
+            } => {
+                param_label(ui, "Types", "A list of mob types");
+                ui.text_edit_singleline(types);
+                param_label(ui, "Amount", "The amount of mobs");
+                amount.handle_ui(ui, id.with("amount"));
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            Condition::MobsInWorld { amount } => {
+                param_label(ui, "Amount", "The amount of mobs");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::MobsNearOrigin {
+                types,
+                amount,
+                radius,
+            } => {
+                param_label(ui, "Types", "A list of mob types");
+                ui.text_edit_singleline(types);
+                param_label(ui, "Amount", "The amount of mobs");
+                amount.handle_ui(ui, id.with("amount"));
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            Condition::MobSize { size } => {
+                param_label(ui, "Size", "The size");
+                size.handle_ui(ui, id.with("size"));
+            }
+            Condition::MoistureLevel { level } => {
+                param_label(ui, "Level", "The moisture level");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::MotionX { velocity } => {
+                param_label(ui, "Velocity", "The velocity");
+                velocity.handle_ui(ui, id.with("velocity"));
+            }
+            Condition::MotionY { velocity } => {
+                param_label(ui, "Velocity", "The velocity");
+                velocity.handle_ui(ui, id.with("velocity"));
+            }
+            Condition::MotionZ { velocity } => {
+                param_label(ui, "Velocity", "The velocity");
+                velocity.handle_ui(ui, id.with("velocity"));
+            }
+            Condition::MythicMobType { types } => {
+                param_label(ui, "Types", "A list of mob internal names");
+                ui.text_edit_singleline(types);
+            }
+            Condition::MythicPack { pack } => {
+                param_label(ui, "Pack", "The name of the pack");
+                ui.text_edit_singleline(pack);
+            }
+            Condition::MythicPackVersion { pack, version } => {
+                param_label(ui, "Pack", "The name of the pack");
+                ui.text_edit_singleline(pack);
+                param_label(ui, "Version", "The version");
+                ui.text_edit_singleline(version);
+            }
+            Condition::MythicPackVersionGreater { pack, version } => {
+                param_label(ui, "Pack", "The name of the pack");
+                ui.text_edit_singleline(pack);
+                param_label(ui, "Version", "The version");
+                ui.text_edit_singleline(version);
+            }
+            Condition::Name { name } => {
+                param_label(ui, "Name", "The name");
+                ui.text_edit_singleline(name);
+            }
+            Condition::NearClaim { radius } => {
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            Condition::NotInRegion { region } => {
+                param_label(ui, "Region", "A list of regions");
+                ui.text_edit_singleline(region);
+            }
+            Condition::OnBlock { blocks } => {
+                param_label(ui, "Blocks", "A list of materials");
+                ui.text_edit_singleline(blocks);
+            }
+            Condition::OriginDistanceFromPin { pin, distance } => {
+                param_label(ui, "Pin", "The name of the pin");
+                ui.text_edit_singleline(pin);
+                param_label(ui, "Distance", "The distance range");
+                distance.handle_ui(ui, id.with("distance"));
+            }
+            Condition::OriginLocation { location } => {
+                param_label(ui, "Location", "The location, as x,y,z");
+                ui.text_edit_singleline(location);
+            }
+            Condition::Pitch { pitch } => {
+                param_label(ui, "Pitch", "The pitch");
+                pitch.handle_ui(ui, id.with("pitch"));
+            }
+            Condition::PlayerKills { kills } => {
+                param_label(ui, "Kills", "The amount of kills");
+                kills.handle_ui(ui, id.with("kills"));
+            }
+            Condition::PlayerNotWithin { distance } => {
+                param_label(ui, "Distance", "The distance");
+                ui.add(egui::DragValue::new(distance).speed(0.1));
+            }
+            Condition::PlayerWithin { distance } => {
+                param_label(ui, "Distance", "The distance");
+                ui.add(egui::DragValue::new(distance).speed(0.1));
+            }
+            Condition::PlayersInRadius { amount, radius } => {
+                param_label(ui, "Amount", "The amount of players");
+                amount.handle_ui(ui, id.with("amount"));
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            Condition::PlayersInWorld { amount } => {
+                param_label(ui, "Amount", "The amount of players");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::PlayersOnline { amount } => {
+                param_label(ui, "Amount", "The amount of players");
+                amount.handle_ui(ui, id.with("amount"));
+            }
+            Condition::Plugin { plugin } => {
+                param_label(ui, "Plugin", "The name of the plugin");
+                ui.text_edit_singleline(plugin);
+            }
+            Condition::Region { region } => {
+                param_label(ui, "Region", "A list of regions");
+                ui.text_edit_singleline(region);
+            }
+            Condition::Score { objective, value } => {
+                param_label(ui, "Objective", "The scoreboard objective");
+                ui.text_edit_singleline(objective);
+                param_label(ui, "Value", "The score");
+                value.handle_ui(ui, id.with("value"));
+            }
+            Condition::HasScoreboardTag { tag } => {
+                param_label(ui, "Tag", "The scoreboard tag");
+                ui.text_edit_singleline(tag);
+            }
+            Condition::SkillLevel { level } => {
+                param_label(ui, "Level", "The level");
+                level.handle_ui(ui, id.with("level"));
+            }
+            Condition::SkillTrigger { trigger } => {
+                param_label(ui, "Trigger", "The trigger, ex: onDamaged");
+                ui.text_edit_singleline(trigger);
+            }
+            Condition::Slot { slot, item } => {
+                param_label(ui, "Slot", "The slot, ex: HAND, HEAD");
+                ui.text_edit_singleline(slot);
+                param_label(ui, "Item", "The item");
+                ui.text_edit_singleline(item);
+            }
+            Condition::SlotType { slot } => {
+                param_label(ui, "Slot", "The slot type");
+                ui.text_edit_singleline(slot);
+            }
+            Condition::Sphere { center, radius } => {
+                param_label(ui, "Center", "The center of the sphere, as x,y,z");
+                ui.text_edit_singleline(center);
+                param_label(ui, "Radius", "The radius of the sphere");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            Condition::StringEquals { value_1, value_2 } => {
+                param_label(ui, "Value 1", "The first string");
+                ui.text_edit_singleline(value_1);
+                param_label(ui, "Value 2", "The second string");
+                ui.text_edit_singleline(value_2);
+            }
+            Condition::StringEqualsIgnoreCase { value_1, value_2 } => {
+                param_label(ui, "Value 1", "The first string");
+                ui.text_edit_singleline(value_1);
+                param_label(ui, "Value 2", "The second string");
+                ui.text_edit_singleline(value_2);
+            }
+            Condition::StringLength { value, length } => {
+                param_label(ui, "Value", "The string");
+                ui.text_edit_singleline(value);
+                param_label(ui, "Length", "The length");
+                length.handle_ui(ui, id.with("length"));
+            }
+            Condition::StringMatches { value, regex } => {
+                param_label(ui, "Value", "The string");
+                ui.text_edit_singleline(value);
+                param_label(ui, "Regex", "The regular expression");
+                ui.text_edit_singleline(regex);
+            }
+            Condition::TargetLocation { location } => {
+                param_label(ui, "Location", "The location, as x,y,z");
+                ui.text_edit_singleline(location);
+            }
+            Condition::Time { time } => {
+                param_label(ui, "Time", "The time in ticks");
+                time.handle_ui(ui, id.with("time"));
+            }
+            Condition::TimeInRadius { radius, time } => {
+                param_label(ui, "Radius", "The radius to check in");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+                param_label(ui, "Time", "The time in ticks");
+                time.handle_ui(ui, id.with("time"));
+            }
+            Condition::Velocity { velocity } => {
+                param_label(ui, "Velocity", "The velocity");
+                velocity.handle_ui(ui, id.with("velocity"));
+            }
+            Condition::World { world } => {
+                param_label(ui, "World", "A list of worlds");
+                ui.text_edit_singleline(world);
+            }
+            Condition::WorldDifficulty { difficulty } => {
+                param_label(ui, "Difficulty", "PEACEFUL, EASY, NORMAL or HARD");
+                ui.text_edit_singleline(difficulty);
+            }
+            Condition::WorldTime { time } => {
+                param_label(ui, "Time", "The time in ticks");
+                time.handle_ui(ui, id.with("time"));
+            }
+            Condition::Yaw { yaw } => {
+                param_label(ui, "Yaw", "The yaw");
+                yaw.handle_ui(ui, id.with("yaw"));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn param_label(ui: &mut egui::Ui, label: &str, hover: &str) {
+    ui.label(label).on_hover_ui(|ui| {
+        ui.label(hover);
+    });
+}
+
+/// What a metaskill does with the result of one of its conditions
+#[derive(
+    Clone, Debug, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter, Default,
+)]
+pub enum ConditionAction {
+    #[strum(to_string = "true")]
+    #[default]
+    /// The condition must be met
+    True,
+
+    #[strum(to_string = "false")]
+    /// The condition must not be met
+    False,
+
+    #[strum(to_string = "cast {0}")]
+    /// Cast the given metaskill if the condition is met
+    Cast(String),
+
+    #[strum(to_string = "castinstead {0}")]
+    /// Cast the given metaskill instead of this one if the condition is met
+    CastInstead(String),
+
+    #[strum(to_string = "power {0}")]
+    /// Multiply the power of the skill if the condition is met
+    Power(f32),
+
+    #[strum(to_string = "orElseCast {0}")]
+    /// Cast the given metaskill instead of this one if the condition is not met
+    OrElseCast(String),
+}
+
+impl ConditionAction {
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            ConditionAction::True => "True",
+            ConditionAction::False => "False",
+            ConditionAction::Cast(_) => "Cast",
+            ConditionAction::CastInstead(_) => "Cast Instead",
+            ConditionAction::Power(_) => "Power",
+            ConditionAction::OrElseCast(_) => "Or Else Cast",
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash) {
+        egui::ComboBox::new(("condition_action", id_salt), "")
+            .selected_text(self.get_fields().into())
+            .show_ui(ui, |ui| {
+                ConditionAction::iter().for_each(|v| {
+                    let label = v.get_fields().into();
+                    ui.selectable_value(self, v, label);
+                });
+            });
+        match self {
+            ConditionAction::Cast(skill)
+            | ConditionAction::CastInstead(skill)
+            | ConditionAction::OrElseCast(skill) => {
+                ui.label("Skill");
+                ui.text_edit_singleline(skill);
+            }
+            ConditionAction::Power(power) => {
+                ui.label("Multiplier");
+                ui.add(egui::DragValue::new(power).speed(0.1));
+            }
+            ConditionAction::True | ConditionAction::False => {}
+        }
+    }
+}
+
+/// An entry of the Conditions, TargetConditions or TriggerConditions of a metaskill
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct SkillCondition {
+    pub condition: Condition,
+    pub action: ConditionAction,
+}
+
+impl std::fmt::Display for SkillCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "- {} {}", self.condition.config(), self.action)
+    }
+}
+
+impl SkillCondition {
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash + Clone) {
+        ui.horizontal(|ui| {
+            self.condition.handle_ui(ui, id_salt.clone());
+            self.action.handle_ui(ui, id_salt);
+        });
+    }
+}
+
+/// A condition written on the skill line itself, ex: `?!burning`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct InlineCondition {
+    pub condition: Condition,
+    /// Checked against the trigger (`?~`) rather than the target (`?`)
+    pub on_trigger: bool,
+    /// The condition must not be met (`?!`)
+    pub negated: bool,
+}
+
+impl std::fmt::Display for InlineCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "?{}{}{}",
+            if self.on_trigger { "~" } else { "" },
+            if self.negated { "!" } else { "" },
+            self.condition.config()
+        )
+    }
+}
+
+impl InlineCondition {
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.on_trigger, "Trigger").on_hover_ui(|ui| {
+                ui.label("Check the condition against the trigger of the skill instead of its target");
+            });
+            ui.checkbox(&mut self.negated, "Not").on_hover_ui(|ui| {
+                ui.label("The skill only runs if the condition is not met");
+            });
+            self.condition.handle_ui(ui, id_salt);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picked(label: &str) -> Condition {
+        Condition::iter()
+            .find(|c| c.get_fields().into() == label)
+            .unwrap()
+            .with_defaults()
+    }
+
+    #[test]
+    fn picked_conditions_get_their_documented_defaults() {
+        assert_eq!(picked("Altitude").config(), "altitude{h=>0;mh=30}");
+        assert_eq!(picked("Chance").config(), "chance{c=0.5}");
+        assert_eq!(picked("Field Of View").config(), "fieldofview{a=90;r=0}");
+    }
+
+    #[test]
+    fn conditions_without_documented_defaults_stay_zeroed() {
+        for label in ["Distance", "Living In Radius", "Name", "Burning"] {
+            let zeroed = Condition::iter()
+                .find(|c| c.get_fields().into() == label)
+                .unwrap();
+            assert_eq!(picked(label), zeroed);
+        }
+        assert_eq!(picked("Name").config(), "name");
+    }
 }
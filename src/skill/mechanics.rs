@@ -3,7 +3,10 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
-#[derive(Deserialize, Serialize, Clone, Default, PartialEq)]
+use super::ProjectNames;
+use super::conditions::{Condition, SkillCondition};
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
 pub enum MythicOption<T> {
     #[default]
     None,
@@ -125,6 +128,39 @@ pub enum Mechanic {
         can_pickup: bool,
     },
 
+    /// Applies an aura to the target, running skills when it starts, ticks and ends
+    #[strum(
+        to_string = "- aura{{auraName={aura_name};d={duration};i={interval};ms={max_stacks};charges={charges};msc={merge_same_caster};rd={refresh_duration};cod={cancel_on_death};cot={cancel_on_teleport};coq={cancel_on_quit}{on_start}{on_tick}{on_end}}}"
+    )]
+    Aura {
+        /// The name of the aura, used to check or remove it
+        aura_name: String,
+        /// How long the aura lasts in ticks
+        duration: u32,
+        /// Ticks between two runs of the tick skill
+        interval: u32,
+        /// How many times the aura can stack on the same target
+        max_stacks: u32,
+        /// How many times the aura can be used before it ends, 0 for unlimited
+        charges: u32,
+        /// Merge the auras of the same caster into one aura
+        merge_same_caster: bool,
+        /// Reapplying the aura resets its duration
+        refresh_duration: bool,
+        /// End the aura when the target dies
+        cancel_on_death: bool,
+        /// End the aura when the target teleports
+        cancel_on_teleport: bool,
+        /// End the aura when the target leaves the server
+        cancel_on_quit: bool,
+        /// Metaskill run when the aura starts
+        on_start: MythicOption<String>,
+        /// Metaskill run every interval
+        on_tick: MythicOption<String>,
+        /// Metaskill run when the aura ends
+        on_end: MythicOption<String>,
+    },
+
     /// Adds an attribute modifier to the attributable target
     #[strum(to_string = "- auraremove{{aura={aura_name};stacks={stacks}}}")]
     AuraRemove {
@@ -282,6 +318,10 @@ pub enum Mechanic {
         items: ItemArray,
     },
 
+    /// Cancels the event that triggered the metaskill, ex: the damage of ~onDamaged
+    #[strum(to_string = "- cancelevent")]
+    CancelEvent,
+
     /// Clears the experience for the targeted players
     #[strum(to_string = "- clearexperience")]
     ClearExperience,
@@ -488,6 +528,10 @@ pub enum Mechanic {
     #[strum(to_string = "- decapitate")]
     Decapitate,
 
+    /// Waits the given number of ticks before running the next mechanics of the metaskill
+    #[strum(to_string = "- delay {0}")]
+    Delay(u32),
+
     /// Copies the appearance of the target player
     #[strum(to_string = "- doppleganger{{nameplate={has_nameplate};upn={use_player_name}}}")]
     Doppleganger {
@@ -663,6 +707,13 @@ pub enum Mechanic {
     #[strum(to_string = "- fly")]
     Fly,
 
+    /// Runs a metaskill once for each of the targets, one at a time
+    #[strum(to_string = "- foreach{{skill={skill}}}")]
+    ForEach {
+        /// The metaskill to run
+        skill: String,
+    },
+
     /// Teleports the target to the caster
     #[strum(to_string = "- forcepull{{s={spread};vs={v_spread}}}")]
     ForcePull {
@@ -937,6 +988,35 @@ pub enum Mechanic {
         audience: String,
     },
 
+    /// Fires a homing projectile that turns towards the target while it travels
+    #[strum(
+        to_string = "- missile{{v={velocity};i={interval};hR={hit_radius};vR={vertical_hit_radius};mr={max_range};in={inertia};hp={hit_players};hnp={hit_non_players}{bullet_type}{skills}{hit_conditions}}}"
+    )]
+    Missile {
+        /// Blocks travelled per second
+        velocity: f32,
+        /// Ticks between two updates of the missile
+        interval: u32,
+        /// Horizontal radius in which entities are hit
+        hit_radius: f32,
+        /// Vertical radius in which entities are hit
+        vertical_hit_radius: f32,
+        /// Blocks travelled before the missile ends
+        max_range: f32,
+        /// How slowly the missile turns towards the target
+        inertia: f32,
+        /// Whether players can be hit
+        hit_players: bool,
+        /// Whether entities other than players can be hit
+        hit_non_players: bool,
+        /// The entity or item shown as the missile
+        bullet_type: MythicOption<BulletType>,
+        /// Metaskills run by the missile
+        skills: ProjectileSkills,
+        /// Conditions checked on each entity before it is hit
+        hit_conditions: HitConditions,
+    },
+
     /// Modifies the damage event that triggered the skill
     #[strum(
         to_string = "- modifyDamage{{amount={amount};damagetype={damage_type};action={action}}}"
@@ -1034,6 +1114,35 @@ pub enum Mechanic {
     #[strum(to_string = "- opentrades{{}}")]
     OpenTrades,
 
+    /// Makes projectiles orbit around the target, they run metaskills when they hit
+    #[strum(
+        to_string = "- orbital{{r={radius};p={points};d={duration};i={interval};hR={hit_radius};vR={vertical_hit_radius};hp={hit_players};hnp={hit_non_players}{bullet_type}{skills}{hit_conditions}}}"
+    )]
+    Orbital {
+        /// Distance between the target and the orbiting projectiles
+        radius: f32,
+        /// Number of points in a full orbit
+        points: u32,
+        /// How long the orbital lasts in ticks
+        duration: u32,
+        /// Ticks between two moves of the projectiles
+        interval: u32,
+        /// Horizontal radius in which entities are hit
+        hit_radius: f32,
+        /// Vertical radius in which entities are hit
+        vertical_hit_radius: f32,
+        /// Whether players can be hit
+        hit_players: bool,
+        /// Whether entities other than players can be hit
+        hit_non_players: bool,
+        /// The entity or item shown as the projectile
+        bullet_type: MythicOption<BulletType>,
+        /// Metaskills run by the orbital
+        skills: ProjectileSkills,
+        /// Conditions checked on each entity before it is hit
+        hit_conditions: HitConditions,
+    },
+
     /// Gives oxygen to a player target
     #[strum(to_string = "- oxygen{{amount={amount}}}")]
     Oxygen {
@@ -1355,6 +1464,37 @@ pub enum Mechanic {
     #[strum(to_string = "- printparenttree{{}}")]
     PrintParentTree,
 
+    /// Fires a custom projectile that runs metaskills while it travels and when it hits
+    #[strum(
+        to_string = "- projectile{{v={velocity};i={interval};hR={hit_radius};vR={vertical_hit_radius};mr={max_range};g={gravity};syo={start_y_offset};hp={hit_players};hnp={hit_non_players}{bullet_type}{skills}{hit_conditions}}}"
+    )]
+    Projectile {
+        /// Blocks travelled per second
+        velocity: f32,
+        /// Ticks between two updates of the projectile
+        interval: u32,
+        /// Horizontal radius in which entities are hit
+        hit_radius: f32,
+        /// Vertical radius in which entities are hit
+        vertical_hit_radius: f32,
+        /// Blocks travelled before the projectile ends
+        max_range: f32,
+        /// Blocks the projectile falls per second
+        gravity: f32,
+        /// Height above the caster the projectile starts from
+        start_y_offset: f32,
+        /// Whether players can be hit
+        hit_players: bool,
+        /// Whether entities other than players can be hit
+        hit_non_players: bool,
+        /// The entity or item shown as the projectile
+        bullet_type: MythicOption<BulletType>,
+        /// Metaskills run by the projectile
+        skills: ProjectileSkills,
+        /// Conditions checked on each entity before it is hit
+        hit_conditions: HitConditions,
+    },
+
     /// Propels the caster towards the target
     #[strum(to_string = "- propel{{velocity={velocity}}}")]
     Propel {
@@ -1402,6 +1542,13 @@ pub enum Mechanic {
         messages: RandomMessages,
     },
 
+    /// Runs one metaskill picked at random from a weighted list
+    #[strum(to_string = "- randomskill{{skills={skills}}}")]
+    RandomSkill {
+        /// The metaskills to pick from
+        skills: WeightedSkills,
+    },
+
     /// Kicks the target's screen in order to simulate a recoil
     #[strum(to_string = "- recoil{{velocity={velocity}}}")]
     Recoil {
@@ -1786,6 +1933,19 @@ pub enum Mechanic {
         stance: String,
     },
 
+    /// Sets a variable of the given scope, the variable is created if it doesn't exist
+    #[strum(to_string = "- setvariable{{var={scope}.{name};type={variable_type};value={value}}}")]
+    SetVariable {
+        /// Who or what holds the variable
+        scope: VariableScope,
+        /// The name of the variable
+        name: String,
+        /// The type of the value
+        variable_type: VariableType,
+        /// The value to set, supports placeholders
+        value: String,
+    },
+
     /// Applies an absorb shield to the target entity
     #[strum(to_string = "- shield{{}}")]
     Shield,
@@ -1801,6 +1961,19 @@ pub enum Mechanic {
         percent: f32,
     },
 
+    /// Shoots a vanilla projectile at the target
+    #[strum(to_string = "- shoot{{type={projectile_type};v={velocity};d={damage}{on_hit}}}")]
+    Shoot {
+        /// The vanilla projectile to shoot
+        projectile_type: ShootType,
+        /// The velocity of the projectile
+        velocity: f32,
+        /// The damage dealt by the projectile
+        damage: f32,
+        /// Metaskill run when the projectile hits
+        on_hit: MythicOption<String>,
+    },
+
     /// Shoots a fireball at the target
     #[strum(to_string = "- shootfireball{{velocity={velocity}}}")]
     ShootFireball {
@@ -1845,6 +2018,13 @@ pub enum Mechanic {
         signal: String,
     },
 
+    /// Runs a metaskill
+    #[strum(to_string = "- skill{{s={skill}}}")]
+    Skill {
+        /// The metaskill to run
+        skill: String,
+    },
+
     /// Alters the target player's skybox
     #[strum(to_string = "- skybox{{skybox={skybox}}}")]
     Skybox {
@@ -1942,6 +2122,13 @@ pub enum Mechanic {
     #[strum(to_string = "- suicide")]
     Suicide,
 
+    /// Makes the target run a metaskill as if it was the caster
+    #[strum(to_string = "- sudoskill{{s={skill}}}")]
+    SudoSkill {
+        /// The metaskill to run
+        skill: String,
+    },
+
     /// Summons a mob at the specified location
     #[strum(to_string = "- summon{{mob={mob};location={location}}}")]
     Summon {
@@ -1996,6 +2183,13 @@ pub enum Mechanic {
     /// Swaps locations with the target
     Swap,
 
+    /// Runs the metaskill of the first case whose condition is met
+    #[strum(to_string = "- switch{{cases={cases}}}")]
+    Switch {
+        /// The cases, checked in order
+        cases: SwitchCases,
+    },
+
     #[strum(to_string = "- swingoffhand")]
     /// Makes the casting player swing their offhand
     SwingOffHand,
@@ -2137,6 +2331,35 @@ pub enum Mechanic {
     /// Sets the sitting state
     ToggleSitting(bool),
 
+    /// Creates a static totem at the target location that runs metaskills on nearby entities
+    #[strum(
+        to_string = "- totem{{md={max_duration};i={interval};hR={hit_radius};vR={vertical_hit_radius};yo={y_offset};charges={charges};hp={hit_players};hnp={hit_non_players}{bullet_type}{skills}{hit_conditions}}}"
+    )]
+    Totem {
+        /// How long the totem lasts in ticks
+        max_duration: u32,
+        /// Ticks between two runs of the tick skill
+        interval: u32,
+        /// Horizontal radius in which entities are hit
+        hit_radius: f32,
+        /// Vertical radius in which entities are hit
+        vertical_hit_radius: f32,
+        /// Height of the totem above the target location
+        y_offset: f32,
+        /// How many hits end the totem, 0 for unlimited
+        charges: u32,
+        /// Whether players can be hit
+        hit_players: bool,
+        /// Whether entities other than players can be hit
+        hit_non_players: bool,
+        /// The entity or item shown as the totem
+        bullet_type: MythicOption<BulletType>,
+        /// Metaskills run by the totem
+        skills: ProjectileSkills,
+        /// Conditions checked on each entity before it is hit
+        hit_conditions: HitConditions,
+    },
+
     #[strum(to_string = "- totemofundying{{mode={model}}}")]
     /// Plays the effect of a totem resurrecting a player with options to specify CustomModelData to use from resource packs.
     TotemOfUndying {
@@ -2200,6 +2423,1562 @@ pub enum Mechanic {
 }
 
 impl Mechanic {
+    /// The mechanic with the defaults of the plugin, `Mechanic::iter()` zeroes every field
+    pub fn with_defaults(self) -> Self {
+        match self {
+            Mechanic::Aura {
+                aura_name,
+                charges,
+                merge_same_caster,
+                refresh_duration,
+                cancel_on_teleport,
+                on_start,
+                on_tick,
+                on_end,
+                ..
+            } => Mechanic::Aura {
+                aura_name,
+                duration: 200,
+                interval: 1,
+                max_stacks: 1,
+                charges,
+                merge_same_caster,
+                refresh_duration,
+                cancel_on_death: true,
+                cancel_on_teleport,
+                cancel_on_quit: true,
+                on_start,
+                on_tick,
+                on_end,
+            },
+            Mechanic::Missile {
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+                ..
+            } => Mechanic::Missile {
+                velocity: 4.0,
+                interval: 1,
+                hit_radius: 2.0,
+                vertical_hit_radius: 2.0,
+                max_range: 40.0,
+                inertia: 1.5,
+                hit_players: true,
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+            },
+            Mechanic::Orbital {
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+                ..
+            } => Mechanic::Orbital {
+                radius: 4.0,
+                points: 32,
+                duration: 100,
+                interval: 1,
+                hit_radius: 1.0,
+                vertical_hit_radius: 1.0,
+                hit_players: true,
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+            },
+            Mechanic::Projectile {
+                gravity,
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+                ..
+            } => Mechanic::Projectile {
+                velocity: 5.0,
+                interval: 1,
+                hit_radius: 2.0,
+                vertical_hit_radius: 2.0,
+                max_range: 40.0,
+                gravity,
+                start_y_offset: 1.0,
+                hit_players: true,
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+            },
+            Mechanic::Shoot {
+                projectile_type,
+                on_hit,
+                ..
+            } => Mechanic::Shoot {
+                projectile_type,
+                velocity: 1.0,
+                damage: 5.0,
+                on_hit,
+            },
+            Mechanic::Totem {
+                y_offset,
+                charges,
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+                ..
+            } => Mechanic::Totem {
+                max_duration: 400,
+                interval: 1,
+                hit_radius: 2.0,
+                vertical_hit_radius: 2.0,
+                y_offset,
+                charges,
+                hit_players: true,
+                hit_non_players,
+                bullet_type,
+                skills,
+                hit_conditions,
+            },
+            mechanic => mechanic,
+        }
+    }
+
+    /// The name of a required parameter left empty, the skill is not written without it
+    pub fn missing_value(&self) -> Option<&'static str> {
+        match self {
+            Mechanic::Aura { aura_name, .. } if aura_name.trim().is_empty() => Some("Aura Name"),
+            Mechanic::Skill { skill }
+            | Mechanic::SudoSkill { skill }
+            | Mechanic::ForEach { skill }
+                if skill.trim().is_empty() =>
+            {
+                Some("Skill")
+            }
+            Mechanic::RandomSkill { skills } if skills.to_string().is_empty() => Some("Skills"),
+            Mechanic::Switch { cases } if cases.to_string().is_empty() => Some("Cases"),
+            _ => None,
+        }
+    }
+
+    /// Set the field a `key=value` argument of a skill line is written from, the key
+    /// is the one of the template or the full name of the field. False if the
+    /// mechanic has no such argument or the value doesn't fit the field
+    pub fn set_arg(&mut self, key: &str, arg: &str) -> bool {
+        let key = key.to_ascii_lowercase();
+        match self {
+            Mechanic::SetVariable {
+                scope,
+                name,
+                variable_type,
+                value,
+            } => match key.as_str() {
+                "var" | "variable" => {
+                    let Some((prefix, variable)) = arg.split_once('.') else {
+                        return false;
+                    };
+                    let Some(s) = VariableScope::iter().find(|s| s.to_string() == prefix) else {
+                        return false;
+                    };
+                    *scope = s;
+                    *name = variable.to_owned();
+                    true
+                }
+                "type" | "t" => {
+                    let Some(t) =
+                        VariableType::iter().find(|t| t.to_string().eq_ignore_ascii_case(arg))
+                    else {
+                        return false;
+                    };
+                    *variable_type = t;
+                    true
+                }
+                "value" | "val" | "v" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::AddTrade { result, .. } => match key.as_str() {
+                "result" => parse_arg(result, arg),
+                _ => false,
+            },
+            Mechanic::AnimateArmorStand {
+                speed, duration, ..
+            } => match key.as_str() {
+                "speed" => parse_arg(speed, arg),
+                "duration" => parse_arg(duration, arg),
+                _ => false,
+            },
+            Mechanic::ArrowVolley {
+                amount,
+                spread,
+                velocity,
+                fire_ticks,
+                remove_delay,
+                can_pickup,
+            } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                "s" | "spread" => parse_arg(spread, arg),
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "f" | "fireticks" => parse_arg(fire_ticks, arg),
+                "rd" | "removedelay" => parse_arg(remove_delay, arg),
+                "pickup" | "canpickup" => parse_arg(can_pickup, arg),
+                _ => false,
+            },
+            Mechanic::Aura {
+                aura_name,
+                duration,
+                interval,
+                max_stacks,
+                charges,
+                merge_same_caster,
+                refresh_duration,
+                cancel_on_death,
+                cancel_on_teleport,
+                cancel_on_quit,
+                ..
+            } => match key.as_str() {
+                "auraname" => parse_arg(aura_name, arg),
+                "d" | "duration" => parse_arg(duration, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                "ms" | "maxstacks" => parse_arg(max_stacks, arg),
+                "charges" => parse_arg(charges, arg),
+                "msc" | "mergesamecaster" => parse_arg(merge_same_caster, arg),
+                "rd" | "refreshduration" => parse_arg(refresh_duration, arg),
+                "cod" | "cancelondeath" => parse_arg(cancel_on_death, arg),
+                "cot" | "cancelonteleport" => parse_arg(cancel_on_teleport, arg),
+                "coq" | "cancelonquit" => parse_arg(cancel_on_quit, arg),
+                _ => false,
+            },
+            Mechanic::AuraRemove { aura_name, stacks } => match key.as_str() {
+                "aura" | "auraname" => parse_arg(aura_name, arg),
+                "stacks" => parse_arg(stacks, arg),
+                _ => false,
+            },
+            Mechanic::BlackScreen { duration } => match key.as_str() {
+                "d" | "duration" => parse_arg(duration, arg),
+                _ => false,
+            },
+            Mechanic::BlockMask {
+                material,
+                radius,
+                radius_y,
+                noise,
+                duration,
+                no_air,
+                only_air,
+                occlude,
+                ..
+            } => match key.as_str() {
+                "m" | "material" => parse_arg(material, arg),
+                "r" | "radius" => parse_arg(radius, arg),
+                "ry" | "radiusy" => parse_arg(radius_y, arg),
+                "n" | "noise" => parse_arg(noise, arg),
+                "d" | "duration" => parse_arg(duration, arg),
+                "na" | "noair" => parse_arg(no_air, arg),
+                "oa" | "onlyair" => parse_arg(only_air, arg),
+                "occ" | "occlude" => parse_arg(occlude, arg),
+                _ => false,
+            },
+            Mechanic::BlockUnmask { radius, .. } => match key.as_str() {
+                "r" | "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::BlockWave {
+                material,
+                radius,
+                radius_y,
+                duration,
+                velocity,
+                horizontal_velocity,
+                specific_velocities,
+                velocity_x,
+                velocity_y,
+                velocity_z,
+                noise,
+                hide_source_block,
+                ignore_air,
+                ..
+            } => match key.as_str() {
+                "m" | "material" => parse_arg(material, arg),
+                "r" | "radius" => parse_arg(radius, arg),
+                "ry" | "radiusy" => parse_arg(radius_y, arg),
+                "d" | "duration" => parse_arg(duration, arg),
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "vh" | "horizontalvelocity" => parse_arg(horizontal_velocity, arg),
+                "sv" | "specificvelocities" => parse_arg(specific_velocities, arg),
+                "vx" | "velocityx" => parse_arg(velocity_x, arg),
+                "vy" | "velocityy" => parse_arg(velocity_y, arg),
+                "vz" | "velocityz" => parse_arg(velocity_z, arg),
+                "n" | "noise" => parse_arg(noise, arg),
+                "hsb" | "hidesourceblock" => parse_arg(hide_source_block, arg),
+                "ia" | "ignoreair" => parse_arg(ignore_air, arg),
+                _ => false,
+            },
+            Mechanic::BloodyScreen { duration, cancel } => match key.as_str() {
+                "d" | "duration" => parse_arg(duration, arg),
+                "c" | "cancel" => parse_arg(cancel, arg),
+                _ => false,
+            },
+            Mechanic::BoneMeal { block_face } => match key.as_str() {
+                "bf" | "blockface" => parse_arg(block_face, arg),
+                _ => false,
+            },
+            Mechanic::BossBorder { radius } => match key.as_str() {
+                "r" | "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::Bouncy {
+                aura_name,
+                on_bounce_skill,
+                cancel_event,
+            } => match key.as_str() {
+                "auraname" => parse_arg(aura_name, arg),
+                "onbounceskill" => parse_arg(on_bounce_skill, arg),
+                "ce" | "cancelevent" => parse_arg(cancel_event, arg),
+                _ => false,
+            },
+            Mechanic::BreakBlock {
+                do_drops,
+                do_effect,
+                use_tool,
+            } => match key.as_str() {
+                "d" | "dodrops" => parse_arg(do_drops, arg),
+                "e" | "doeffect" => parse_arg(do_effect, arg),
+                "t" | "usetool" => parse_arg(use_tool, arg),
+                _ => false,
+            },
+            Mechanic::BreakBlockAndGiveItem {
+                do_drops,
+                do_effect,
+                use_tool,
+                do_fake_looting,
+                ..
+            } => match key.as_str() {
+                "d" | "dodrops" => parse_arg(do_drops, arg),
+                "e" | "doeffect" => parse_arg(do_effect, arg),
+                "t" | "usetool" => parse_arg(use_tool, arg),
+                "fl" | "dofakelooting" => parse_arg(do_fake_looting, arg),
+                _ => false,
+            },
+            Mechanic::GiveExperienceLevels { amount } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::TakeExperienceLevels { amount } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::Command {
+                command,
+                as_caster,
+                as_op,
+                as_target,
+                require_target,
+            } => match key.as_str() {
+                "c" | "command" => parse_arg(command, arg),
+                "ac" | "ascaster" => parse_arg(as_caster, arg),
+                "op" | "asop" => parse_arg(as_op, arg),
+                "at" | "astarget" => parse_arg(as_target, arg),
+                "rt" | "requiretarget" => parse_arg(require_target, arg),
+                _ => false,
+            },
+            Mechanic::Consume { damage, heal } => match key.as_str() {
+                "d" | "damage" => parse_arg(damage, arg),
+                "h" | "heal" => parse_arg(heal, arg),
+                _ => false,
+            },
+            Mechanic::ConsumeSlot { slot, amount } => match key.as_str() {
+                "s" | "slot" => parse_arg(slot, arg),
+                "a" | "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::DirectionalVelocity {
+                yaw,
+                pitch,
+                velocity,
+                mode,
+            } => match key.as_str() {
+                "yaw" => parse_arg(yaw, arg),
+                "pitch" => parse_arg(pitch, arg),
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "m" | "mode" => parse_arg(mode, arg),
+                _ => false,
+            },
+            Mechanic::Disengage {
+                velocity,
+                velocity_y,
+            } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "vy" | "velocityy" => parse_arg(velocity_y, arg),
+                _ => false,
+            },
+            Mechanic::Disguise { disguise } => match key.as_str() {
+                "d" | "disguise" => parse_arg(disguise, arg),
+                _ => false,
+            },
+            Mechanic::DisguiseModify { disguise } => match key.as_str() {
+                "d" | "disguise" => parse_arg(disguise, arg),
+                _ => false,
+            },
+            Mechanic::DisguiseTarget { disguise } => match key.as_str() {
+                "d" | "disguise" => parse_arg(disguise, arg),
+                _ => false,
+            },
+            Mechanic::DisplayTransformation {
+                action,
+                transformation_type,
+                value,
+            } => match key.as_str() {
+                "a" | "action" => parse_arg(action, arg),
+                "tt" | "transformationtype" => parse_arg(transformation_type, arg),
+                "val" | "value" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::CurrencyGive { amount } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::CurrencyTake { amount } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::Damage {
+                amount,
+                ignore_armor,
+                prevent_knockback,
+                prevent_immunity,
+                damage_cause,
+                ignore_enchantments,
+                no_anger,
+                ignore_invulnerability,
+                ignore_shield,
+                damage_helmet,
+                ignore_effects,
+                ignore_resistance,
+                power_affects_damage,
+                element,
+                trigger_skills,
+                ..
+            } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                "ia" | "ignorearmor" => parse_arg(ignore_armor, arg),
+                "pkb" | "preventknockback" => parse_arg(prevent_knockback, arg),
+                "pi" | "preventimmunity" => parse_arg(prevent_immunity, arg),
+                "dc" | "damagecause" => parse_arg(damage_cause, arg),
+                "ie" | "ignoreenchantments" => parse_arg(ignore_enchantments, arg),
+                "na" | "noanger" => parse_arg(no_anger, arg),
+                "ii" | "ignoreinvulnerability" => parse_arg(ignore_invulnerability, arg),
+                "is" | "ignoreshield" => parse_arg(ignore_shield, arg),
+                "dh" | "damagehelmet" => parse_arg(damage_helmet, arg),
+                "ieff" | "ignoreeffects" => parse_arg(ignore_effects, arg),
+                "ir" | "ignoreresistance" => parse_arg(ignore_resistance, arg),
+                "pad" | "poweraffectsdamage" => parse_arg(power_affects_damage, arg),
+                "e" | "element" => parse_arg(element, arg),
+                "ts" | "triggerskills" => parse_arg(trigger_skills, arg),
+                _ => false,
+            },
+            Mechanic::BaseDamage {
+                multiplier,
+                use_attribute,
+            } => match key.as_str() {
+                "m" | "multiplier" => parse_arg(multiplier, arg),
+                "attr" | "useattribute" => parse_arg(use_attribute, arg),
+                _ => false,
+            },
+            Mechanic::PercentDamage {
+                percent,
+                current_health,
+            } => match key.as_str() {
+                "p" | "percent" => parse_arg(percent, arg),
+                "c" | "currenthealth" => parse_arg(current_health, arg),
+                _ => false,
+            },
+            Mechanic::Doppleganger { has_nameplate, .. } => match key.as_str() {
+                "nameplate" | "hasnameplate" => parse_arg(has_nameplate, arg),
+                _ => false,
+            },
+            Mechanic::DropItem {
+                items, naturally, ..
+            } => match key.as_str() {
+                "i" | "items" => parse_arg(items, arg),
+                "n" | "naturally" => parse_arg(naturally, arg),
+                _ => false,
+            },
+            Mechanic::EnderBeam { duration, y_offset } => match key.as_str() {
+                "d" | "duration" => parse_arg(duration, arg),
+                "y" | "yoffset" => parse_arg(y_offset, arg),
+                _ => false,
+            },
+            Mechanic::EnderDragonSetPhase { phase } => match key.as_str() {
+                "p" | "phase" => parse_arg(phase, arg),
+                _ => false,
+            },
+            Mechanic::EnderDragonSetRespawnPhase { phase } => match key.as_str() {
+                "p" | "phase" => parse_arg(phase, arg),
+                _ => false,
+            },
+            Mechanic::EnderDragonSpawnPortal { with_portals } => match key.as_str() {
+                "wp" | "withportals" => parse_arg(with_portals, arg),
+                _ => false,
+            },
+            Mechanic::EquipCopy { slots } => match key.as_str() {
+                "s" | "slots" => parse_arg(slots, arg),
+                _ => false,
+            },
+            Mechanic::Explosion {
+                power_explosion,
+                block_damage,
+                fire,
+            } => match key.as_str() {
+                "y" | "powerexplosion" => parse_arg(power_explosion, arg),
+                "bd" | "blockdamage" => parse_arg(block_damage, arg),
+                "f" | "fire" => parse_arg(fire, arg),
+                _ => false,
+            },
+            Mechanic::FawePaste {
+                schematic,
+                paste_air,
+                x_offset,
+                y_offset,
+                z_offset,
+                rotation,
+                center,
+                blocks_per_tick,
+                duration,
+                ..
+            } => match key.as_str() {
+                "s" | "schematic" => parse_arg(schematic, arg),
+                "a" | "pasteair" => parse_arg(paste_air, arg),
+                "x" | "xoffset" => parse_arg(x_offset, arg),
+                "y" | "yoffset" => parse_arg(y_offset, arg),
+                "z" | "zoffset" => parse_arg(z_offset, arg),
+                "rot" | "rotation" => parse_arg(rotation, arg),
+                "c" | "center" => parse_arg(center, arg),
+                "bpt" | "blockspertick" => parse_arg(blocks_per_tick, arg),
+                "d" | "duration" => parse_arg(duration, arg),
+                _ => false,
+            },
+            Mechanic::Feed {
+                amount,
+                saturation,
+                overfeed,
+            } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                "s" | "saturation" => parse_arg(saturation, arg),
+                "o" | "overfeed" => parse_arg(overfeed, arg),
+                _ => false,
+            },
+            Mechanic::FillChest { items, .. } => match key.as_str() {
+                "i" | "items" => parse_arg(items, arg),
+                _ => false,
+            },
+            Mechanic::Firework {
+                firework_type,
+                power,
+                flicker,
+                trail,
+                colors,
+                fade_colors,
+            } => match key.as_str() {
+                "t" | "fireworktype" => parse_arg(firework_type, arg),
+                "p" | "power" => parse_arg(power, arg),
+                "f" | "flicker" => parse_arg(flicker, arg),
+                "tr" | "trail" => parse_arg(trail, arg),
+                "c" | "colors" => parse_arg(colors, arg),
+                "fc" | "fadecolors" => parse_arg(fade_colors, arg),
+                _ => false,
+            },
+            Mechanic::ForEach { skill } => match key.as_str() {
+                "skill" => parse_arg(skill, arg),
+                _ => false,
+            },
+            Mechanic::ForcePull { spread, .. } => match key.as_str() {
+                "s" | "spread" => parse_arg(spread, arg),
+                _ => false,
+            },
+            Mechanic::Freeze { ticks } => match key.as_str() {
+                "t" | "ticks" => parse_arg(ticks, arg),
+                _ => false,
+            },
+            Mechanic::Geyser {
+                liquid_type,
+                height,
+                interval,
+            } => match key.as_str() {
+                "t" | "liquidtype" => parse_arg(liquid_type, arg),
+                "h" | "height" => parse_arg(height, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                _ => false,
+            },
+            Mechanic::GiveItem { item, fake_looting } => match key.as_str() {
+                "i" | "item" => parse_arg(item, arg),
+                "fl" | "fakelooting" => parse_arg(fake_looting, arg),
+                _ => false,
+            },
+            Mechanic::GiveItemFromSlot { slot, fake_looting } => match key.as_str() {
+                "s" | "slot" => parse_arg(slot, arg),
+                "fl" | "fakelooting" => parse_arg(fake_looting, arg),
+                _ => false,
+            },
+            Mechanic::GiveItemFromTarget { item, fake_looting } => match key.as_str() {
+                "i" | "item" => parse_arg(item, arg),
+                "fl" | "fakelooting" => parse_arg(fake_looting, arg),
+                _ => false,
+            },
+            Mechanic::Glow { color } => match key.as_str() {
+                "color" => parse_arg(color, arg),
+                _ => false,
+            },
+            Mechanic::GoTo {
+                speed,
+                spread_h,
+                spread_v,
+            } => match key.as_str() {
+                "s" | "speed" => parse_arg(speed, arg),
+                "sh" | "spreadh" => parse_arg(spread_h, arg),
+                "sv" | "spreadv" => parse_arg(spread_v, arg),
+                _ => false,
+            },
+            Mechanic::GuardianBeam {
+                duration,
+                interval,
+                start_y_offset,
+                target_y_offset,
+                from_origin,
+                ..
+            } => match key.as_str() {
+                "d" | "duration" => parse_arg(duration, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                "syo" | "startyoffset" => parse_arg(start_y_offset, arg),
+                "tyo" | "targetyoffset" => parse_arg(target_y_offset, arg),
+                "fo" | "fromorigin" => parse_arg(from_origin, arg),
+                _ => false,
+            },
+            Mechanic::Heal {
+                amount,
+                overheal,
+                max_overheal,
+            } => match key.as_str() {
+                "a" | "amount" => parse_arg(amount, arg),
+                "oh" | "overheal" => parse_arg(overheal, arg),
+                "mo" | "maxoverheal" => parse_arg(max_overheal, arg),
+                _ => false,
+            },
+            Mechanic::HealPercent {
+                overheal,
+                max_overheal,
+                ..
+            } => match key.as_str() {
+                "oh" | "overheal" => parse_arg(overheal, arg),
+                "mo" | "maxoverheal" => parse_arg(max_overheal, arg),
+                _ => false,
+            },
+            Mechanic::Hide {
+                ignore_aura_options,
+            } => match key.as_str() {
+                "ignoreauraoptions" => parse_arg(ignore_aura_options, arg),
+                _ => false,
+            },
+            Mechanic::Hit {
+                multiplier,
+                trigger_skills,
+                scale_by_attack_cooldown,
+                ..
+            } => match key.as_str() {
+                "m" | "multiplier" => parse_arg(multiplier, arg),
+                "ts" | "triggerskills" => parse_arg(trigger_skills, arg),
+                "sbac" | "scalebyattackcooldown" => parse_arg(scale_by_attack_cooldown, arg),
+                _ => false,
+            },
+            Mechanic::Hologram { text, stay } => match key.as_str() {
+                "text" => parse_arg(text, arg),
+                "time" | "stay" => parse_arg(stay, arg),
+                _ => false,
+            },
+            Mechanic::Ignite { ticks } => match key.as_str() {
+                "t" | "ticks" => parse_arg(ticks, arg),
+                _ => false,
+            },
+            Mechanic::ItemSpray {
+                items,
+                amount,
+                duration,
+                radius,
+                velocity,
+                y_offset,
+                allow_pickup,
+                gravity,
+                ..
+            } => match key.as_str() {
+                "i" | "items" => parse_arg(items, arg),
+                "a" | "amount" => parse_arg(amount, arg),
+                "d" | "duration" => parse_arg(duration, arg),
+                "r" | "radius" => parse_arg(radius, arg),
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "yo" | "yoffset" => parse_arg(y_offset, arg),
+                "ap" | "allowpickup" => parse_arg(allow_pickup, arg),
+                "g" | "gravity" => parse_arg(gravity, arg),
+                _ => false,
+            },
+            Mechanic::JSONMessage { message } => match key.as_str() {
+                "m" | "message" => parse_arg(message, arg),
+                _ => false,
+            },
+            Mechanic::Jump { velocity } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::Leap { velocity, noise } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "n" | "noise" => parse_arg(noise, arg),
+                _ => false,
+            },
+            Mechanic::Lightning { damage } => match key.as_str() {
+                "damage" => parse_arg(damage, arg),
+                _ => false,
+            },
+            Mechanic::FakeLightning {
+                localized,
+                localized_radius,
+            } => match key.as_str() {
+                "localized" => parse_arg(localized, arg),
+                "localizedradius" => parse_arg(localized_radius, arg),
+                _ => false,
+            },
+            Mechanic::Log { message } => match key.as_str() {
+                "message" => parse_arg(message, arg),
+                _ => false,
+            },
+            Mechanic::Look {
+                head_only,
+                force,
+                force_paper,
+                immediately,
+            } => match key.as_str() {
+                "headonly" => parse_arg(head_only, arg),
+                "force" => parse_arg(force, arg),
+                "forcepaper" => parse_arg(force_paper, arg),
+                "immediately" => parse_arg(immediately, arg),
+                _ => false,
+            },
+            Mechanic::Lunge {
+                velocity,
+                velocity_y,
+                old_math,
+            } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                "velocityy" => parse_arg(velocity_y, arg),
+                "oldmath" => parse_arg(old_math, arg),
+                _ => false,
+            },
+            Mechanic::MatchRotation { target } => match key.as_str() {
+                "target" => parse_arg(target, arg),
+                _ => false,
+            },
+            Mechanic::Message { message, audience } => match key.as_str() {
+                "message" => parse_arg(message, arg),
+                "audience" => parse_arg(audience, arg),
+                _ => false,
+            },
+            Mechanic::Missile {
+                velocity,
+                interval,
+                hit_radius,
+                vertical_hit_radius,
+                max_range,
+                inertia,
+                hit_players,
+                hit_non_players,
+                ..
+            } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                "hr" | "hitradius" => parse_arg(hit_radius, arg),
+                "vr" | "verticalhitradius" => parse_arg(vertical_hit_radius, arg),
+                "mr" | "maxrange" => parse_arg(max_range, arg),
+                "in" | "inertia" => parse_arg(inertia, arg),
+                "hp" | "hitplayers" => parse_arg(hit_players, arg),
+                "hnp" | "hitnonplayers" => parse_arg(hit_non_players, arg),
+                _ => false,
+            },
+            Mechanic::ModifyDamage {
+                amount,
+                damage_type,
+                action,
+            } => match key.as_str() {
+                "amount" => parse_arg(amount, arg),
+                "damagetype" => parse_arg(damage_type, arg),
+                "action" => parse_arg(action, arg),
+                _ => false,
+            },
+            Mechanic::ModifyGlobalScore {
+                objective,
+                action,
+                value,
+            } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "action" => parse_arg(action, arg),
+                "value" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::ModifyTargetScore {
+                objective,
+                action,
+                value,
+            } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "action" => parse_arg(action, arg),
+                "value" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::ModifyMobScore {
+                objective,
+                action,
+                value,
+            } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "action" => parse_arg(action, arg),
+                "value" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::ModifyScore {
+                objective,
+                action,
+                value,
+            } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "action" => parse_arg(action, arg),
+                "value" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::Mount { entity } => match key.as_str() {
+                "entity" => parse_arg(entity, arg),
+                _ => false,
+            },
+            Mechanic::MountMe { entity } => match key.as_str() {
+                "entity" => parse_arg(entity, arg),
+                _ => false,
+            },
+            Mechanic::MountTarget { entity } => match key.as_str() {
+                "entity" => parse_arg(entity, arg),
+                _ => false,
+            },
+            Mechanic::MovePin { x, y, z } => match key.as_str() {
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                _ => false,
+            },
+            Mechanic::Orbital {
+                radius,
+                points,
+                duration,
+                interval,
+                hit_radius,
+                vertical_hit_radius,
+                hit_players,
+                hit_non_players,
+                ..
+            } => match key.as_str() {
+                "r" | "radius" => parse_arg(radius, arg),
+                "p" | "points" => parse_arg(points, arg),
+                "d" | "duration" => parse_arg(duration, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                "hr" | "hitradius" => parse_arg(hit_radius, arg),
+                "vr" | "verticalhitradius" => parse_arg(vertical_hit_radius, arg),
+                "hp" | "hitplayers" => parse_arg(hit_players, arg),
+                "hnp" | "hitnonplayers" => parse_arg(hit_non_players, arg),
+                _ => false,
+            },
+            Mechanic::Oxygen { amount } => match key.as_str() {
+                "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::Particle {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                _ => false,
+            },
+            Mechanic::ParticleBox {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                width,
+                height,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "width" => parse_arg(width, arg),
+                "height" => parse_arg(height, arg),
+                _ => false,
+            },
+            Mechanic::ParticleEquation {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                equation,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "equation" => parse_arg(equation, arg),
+                _ => false,
+            },
+            Mechanic::ParticleLine {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                length,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "length" => parse_arg(length, arg),
+                _ => false,
+            },
+            Mechanic::ParticleLineHelix {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                length,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "length" => parse_arg(length, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::ParticleLineRing {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::ParticleOrbital {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::ParticleRing {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::ParticleSphere {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::ParticleTornado {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                height,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "height" => parse_arg(height, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::Atom {
+                particle,
+                amount,
+                speed,
+                x,
+                y,
+                z,
+                radius,
+            } => match key.as_str() {
+                "particle" => parse_arg(particle, arg),
+                "amount" => parse_arg(amount, arg),
+                "speed" => parse_arg(speed, arg),
+                "x" => parse_arg(x, arg),
+                "y" => parse_arg(y, arg),
+                "z" => parse_arg(z, arg),
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::PickUpItem { item } => match key.as_str() {
+                "item" => parse_arg(item, arg),
+                _ => false,
+            },
+            Mechanic::PlayAnimation { animation } => match key.as_str() {
+                "animation" => parse_arg(animation, arg),
+                _ => false,
+            },
+            Mechanic::PlayBlockBreakSound { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::PlayBlockFallSound { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::PlayBlockHitSound { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::PlayBlockPlaceSound { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::PlayBlockStepSound { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::PoseArmorStand { pose } => match key.as_str() {
+                "pose" => parse_arg(pose, arg),
+                _ => false,
+            },
+            Mechanic::Potion {
+                potion,
+                duration,
+                level,
+            } => match key.as_str() {
+                "potion" => parse_arg(potion, arg),
+                "duration" => parse_arg(duration, arg),
+                "level" => parse_arg(level, arg),
+                _ => false,
+            },
+            Mechanic::Projectile {
+                velocity,
+                interval,
+                hit_radius,
+                vertical_hit_radius,
+                max_range,
+                gravity,
+                start_y_offset,
+                hit_players,
+                hit_non_players,
+                ..
+            } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                "hr" | "hitradius" => parse_arg(hit_radius, arg),
+                "vr" | "verticalhitradius" => parse_arg(vertical_hit_radius, arg),
+                "mr" | "maxrange" => parse_arg(max_range, arg),
+                "g" | "gravity" => parse_arg(gravity, arg),
+                "syo" | "startyoffset" => parse_arg(start_y_offset, arg),
+                "hp" | "hitplayers" => parse_arg(hit_players, arg),
+                "hnp" | "hitnonplayers" => parse_arg(hit_non_players, arg),
+                _ => false,
+            },
+            Mechanic::Propel { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::Pull { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::PushBlock { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::Rally { radius } => match key.as_str() {
+                "radius" => parse_arg(radius, arg),
+                _ => false,
+            },
+            Mechanic::Recoil { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::RotateTowards { target } => match key.as_str() {
+                "target" => parse_arg(target, arg),
+                _ => false,
+            },
+            Mechanic::SendActionMessage { message } => match key.as_str() {
+                "message" => parse_arg(message, arg),
+                _ => false,
+            },
+            Mechanic::SendResourcePack { url } => match key.as_str() {
+                "url" => parse_arg(url, arg),
+                _ => false,
+            },
+            Mechanic::SendTitle {
+                title,
+                subtitle,
+                fade_in,
+                stay,
+                fade_out,
+            } => match key.as_str() {
+                "title" => parse_arg(title, arg),
+                "subtitle" => parse_arg(subtitle, arg),
+                "fadein" => parse_arg(fade_in, arg),
+                "stay" => parse_arg(stay, arg),
+                "fadeout" => parse_arg(fade_out, arg),
+                _ => false,
+            },
+            Mechanic::SendToast { title, message } => match key.as_str() {
+                "title" => parse_arg(title, arg),
+                "message" => parse_arg(message, arg),
+                _ => false,
+            },
+            Mechanic::SetAI { ai } => match key.as_str() {
+                "ai" => parse_arg(ai, arg),
+                _ => false,
+            },
+            Mechanic::SetBlockOpen { open } => match key.as_str() {
+                "open" => parse_arg(open, arg),
+                _ => false,
+            },
+            Mechanic::SetBlockType { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::SetChunkForceLoaded { loaded } => match key.as_str() {
+                "loaded" => parse_arg(loaded, arg),
+                _ => false,
+            },
+            Mechanic::SetCollidable { collidable } => match key.as_str() {
+                "collidable" => parse_arg(collidable, arg),
+                _ => false,
+            },
+            Mechanic::SetDragonPodium { podium } => match key.as_str() {
+                "podium" => parse_arg(podium, arg),
+                _ => false,
+            },
+            Mechanic::SetGameMode { gamemode } => match key.as_str() {
+                "gamemode" => parse_arg(gamemode, arg),
+                _ => false,
+            },
+            Mechanic::SetGliding { gliding } => match key.as_str() {
+                "gliding" => parse_arg(gliding, arg),
+                _ => false,
+            },
+            Mechanic::SetGlobalScore {
+                objective,
+                action,
+                value,
+            } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "action" => parse_arg(action, arg),
+                "value" => parse_arg(value, arg),
+                _ => false,
+            },
+            Mechanic::SetGravity { gravity } => match key.as_str() {
+                "gravity" => parse_arg(gravity, arg),
+                _ => false,
+            },
+            Mechanic::SetHealth { health } => match key.as_str() {
+                "health" => parse_arg(health, arg),
+                _ => false,
+            },
+            Mechanic::SetInteractionSize { width, height } => match key.as_str() {
+                "width" => parse_arg(width, arg),
+                "height" => parse_arg(height, arg),
+                _ => false,
+            },
+            Mechanic::SetItemGroupCooldown { group, cooldown } => match key.as_str() {
+                "group" => parse_arg(group, arg),
+                "cooldown" => parse_arg(cooldown, arg),
+                _ => false,
+            },
+            Mechanic::SetDisplayEntityItem { item } => match key.as_str() {
+                "item" => parse_arg(item, arg),
+                _ => false,
+            },
+            Mechanic::SetLeashHolder { holder } => match key.as_str() {
+                "holder" => parse_arg(holder, arg),
+                _ => false,
+            },
+            Mechanic::SetLevel { level } => match key.as_str() {
+                "level" => parse_arg(level, arg),
+                _ => false,
+            },
+            Mechanic::SetMaterialCooldown { material, cooldown } => match key.as_str() {
+                "material" => parse_arg(material, arg),
+                "cooldown" => parse_arg(cooldown, arg),
+                _ => false,
+            },
+            Mechanic::SetMaxHealth { health } => match key.as_str() {
+                "health" => parse_arg(health, arg),
+                _ => false,
+            },
+            Mechanic::SetMobColor { color } => match key.as_str() {
+                "color" => parse_arg(color, arg),
+                _ => false,
+            },
+            Mechanic::SetMobScore { objective, score } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "score" => parse_arg(score, arg),
+                _ => false,
+            },
+            Mechanic::SetName { name } => match key.as_str() {
+                "name" => parse_arg(name, arg),
+                _ => false,
+            },
+            Mechanic::SetRaiderCanJoinRaid { can_join } => match key.as_str() {
+                "can_join" | "canjoin" => parse_arg(can_join, arg),
+                _ => false,
+            },
+            Mechanic::SetRaiderPatrolBlock { block } => match key.as_str() {
+                "block" => parse_arg(block, arg),
+                _ => false,
+            },
+            Mechanic::SetRaiderPatrolLeader { leader } => match key.as_str() {
+                "leader" => parse_arg(leader, arg),
+                _ => false,
+            },
+            Mechanic::SetFaction { faction } => match key.as_str() {
+                "faction" => parse_arg(faction, arg),
+                _ => false,
+            },
+            Mechanic::SetFlying { flying } => match key.as_str() {
+                "flying" => parse_arg(flying, arg),
+                _ => false,
+            },
+            Mechanic::SetNoDamageTicks { ticks } => match key.as_str() {
+                "ticks" => parse_arg(ticks, arg),
+                _ => false,
+            },
+            Mechanic::SetOwner { owner } => match key.as_str() {
+                "owner" => parse_arg(owner, arg),
+                _ => false,
+            },
+            Mechanic::SetParent { parent } => match key.as_str() {
+                "parent" => parse_arg(parent, arg),
+                _ => false,
+            },
+            Mechanic::SetPathfindingMalus { malus } => match key.as_str() {
+                "malus" => parse_arg(malus, arg),
+                _ => false,
+            },
+            Mechanic::SetPitch { pitch } => match key.as_str() {
+                "pitch" => parse_arg(pitch, arg),
+                _ => false,
+            },
+            Mechanic::SetPose { pose } => match key.as_str() {
+                "pose" => parse_arg(pose, arg),
+                _ => false,
+            },
+            Mechanic::SetRotation { yaw, pitch } => match key.as_str() {
+                "yaw" => parse_arg(yaw, arg),
+                "pitch" => parse_arg(pitch, arg),
+                _ => false,
+            },
+            Mechanic::SetTarget { target } => match key.as_str() {
+                "target" => parse_arg(target, arg),
+                _ => false,
+            },
+            Mechanic::SetTargetScore { objective, score } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "score" => parse_arg(score, arg),
+                _ => false,
+            },
+            Mechanic::SetTextDisplay { text } => match key.as_str() {
+                "text" => parse_arg(text, arg),
+                _ => false,
+            },
+            Mechanic::SetTongueTarget { target } => match key.as_str() {
+                "target" => parse_arg(target, arg),
+                _ => false,
+            },
+            Mechanic::SetScore { objective, score } => match key.as_str() {
+                "objective" => parse_arg(objective, arg),
+                "score" => parse_arg(score, arg),
+                _ => false,
+            },
+            Mechanic::SetSpeed { speed } => match key.as_str() {
+                "speed" => parse_arg(speed, arg),
+                _ => false,
+            },
+            Mechanic::SetStance { stance } => match key.as_str() {
+                "stance" => parse_arg(stance, arg),
+                _ => false,
+            },
+            Mechanic::ShieldPercent { percent } => match key.as_str() {
+                "percent" => parse_arg(percent, arg),
+                _ => false,
+            },
+            Mechanic::Shoot {
+                velocity, damage, ..
+            } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "d" | "damage" => parse_arg(damage, arg),
+                _ => false,
+            },
+            Mechanic::ShootFireball { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::ShootPotion { potion, velocity } => match key.as_str() {
+                "potion" => parse_arg(potion, arg),
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::ShootSkull { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::ShootShulkerBullet { velocity } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::ShowEntity { entity } => match key.as_str() {
+                "entity" => parse_arg(entity, arg),
+                _ => false,
+            },
+            Mechanic::Signal { signal } => match key.as_str() {
+                "signal" => parse_arg(signal, arg),
+                _ => false,
+            },
+            Mechanic::Skill { skill } => match key.as_str() {
+                "s" | "skill" => parse_arg(skill, arg),
+                _ => false,
+            },
+            Mechanic::Skybox { skybox } => match key.as_str() {
+                "skybox" => parse_arg(skybox, arg),
+                _ => false,
+            },
+            Mechanic::Sound {
+                sound,
+                volume,
+                pitch,
+            } => match key.as_str() {
+                "sound" => parse_arg(sound, arg),
+                "volume" => parse_arg(volume, arg),
+                "pitch" => parse_arg(pitch, arg),
+                _ => false,
+            },
+            Mechanic::StealItem { item } => match key.as_str() {
+                "item" => parse_arg(item, arg),
+                _ => false,
+            },
+            Mechanic::StopSound { sound } => match key.as_str() {
+                "s" | "sound" => parse_arg(sound, arg),
+                _ => false,
+            },
+            Mechanic::StopSoundWithCategory { sound, .. } => match key.as_str() {
+                "s" | "sound" => parse_arg(sound, arg),
+                _ => false,
+            },
+            Mechanic::Spin { velocity, .. } => match key.as_str() {
+                "velocity" => parse_arg(velocity, arg),
+                _ => false,
+            },
+            Mechanic::Stun { duration } => match key.as_str() {
+                "duration" => parse_arg(duration, arg),
+                _ => false,
+            },
+            Mechanic::SudoSkill { skill } => match key.as_str() {
+                "s" | "skill" => parse_arg(skill, arg),
+                _ => false,
+            },
+            Mechanic::Summon { mob, location } => match key.as_str() {
+                "mob" => parse_arg(mob, arg),
+                "location" => parse_arg(location, arg),
+                _ => false,
+            },
+            Mechanic::SummonFallingBlock { material } => match key.as_str() {
+                "m" | "material" => parse_arg(material, arg),
+                _ => false,
+            },
+            Mechanic::SummonPassenger { passenger, .. } => match key.as_str() {
+                "type" | "passenger" => parse_arg(passenger, arg),
+                _ => false,
+            },
+            Mechanic::AddTag(field) => match key.as_str() {
+                "t" => parse_arg(field, arg),
+                _ => false,
+            },
+            Mechanic::RemoveTag(field) => match key.as_str() {
+                "t" => parse_arg(field, arg),
+                _ => false,
+            },
+            Mechanic::TakeItem { item, amount, .. } => match key.as_str() {
+                "i" | "item" => parse_arg(item, arg),
+                "a" | "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::TeleportY { y } => match key.as_str() {
+                "y" => parse_arg(y, arg),
+                _ => false,
+            },
+            Mechanic::TeleportTo { world, .. } => match key.as_str() {
+                "w" | "world" => parse_arg(world, arg),
+                _ => false,
+            },
+            Mechanic::Time { amount, .. } => match key.as_str() {
+                "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::Threat { amount, .. } => match key.as_str() {
+                "amount" => parse_arg(amount, arg),
+                _ => false,
+            },
+            Mechanic::Throw {
+                velocity,
+                velocity_y,
+                ..
+            } => match key.as_str() {
+                "v" | "velocity" => parse_arg(velocity, arg),
+                "vy" | "velocityy" => parse_arg(velocity_y, arg),
+                _ => false,
+            },
+            Mechanic::ToggleLever { duration, .. } => match key.as_str() {
+                "duration" => parse_arg(duration, arg),
+                _ => false,
+            },
+            Mechanic::ToggleSitting(field) => match key.as_str() {
+                "state" => parse_arg(field, arg),
+                _ => false,
+            },
+            Mechanic::Totem {
+                max_duration,
+                interval,
+                hit_radius,
+                vertical_hit_radius,
+                y_offset,
+                charges,
+                hit_players,
+                hit_non_players,
+                ..
+            } => match key.as_str() {
+                "md" | "maxduration" => parse_arg(max_duration, arg),
+                "i" | "interval" => parse_arg(interval, arg),
+                "hr" | "hitradius" => parse_arg(hit_radius, arg),
+                "vr" | "verticalhitradius" => parse_arg(vertical_hit_radius, arg),
+                "yo" | "yoffset" => parse_arg(y_offset, arg),
+                "charges" => parse_arg(charges, arg),
+                "hp" | "hitplayers" => parse_arg(hit_players, arg),
+                "hnp" | "hitnonplayers" => parse_arg(hit_non_players, arg),
+                _ => false,
+            },
+            Mechanic::TotemOfUndying { model } => match key.as_str() {
+                "mode" | "model" => parse_arg(model, arg),
+                _ => false,
+            },
+            Mechanic::UndoPaste { paste_id } => match key.as_str() {
+                "id" | "pasteid" => parse_arg(paste_id, arg),
+                _ => false,
+            },
+            Mechanic::Velocity {
+                velocity_x,
+                velocity_y,
+                velocity_z,
+                ..
+            } => match key.as_str() {
+                "x" | "velocityx" => parse_arg(velocity_x, arg),
+                "y" | "velocityy" => parse_arg(velocity_y, arg),
+                "z" | "velocityz" => parse_arg(velocity_z, arg),
+                _ => false,
+            },
+            Mechanic::WolfSit(field) => match key.as_str() {
+                "state" => parse_arg(field, arg),
+                _ => false,
+            },
+            Mechanic::WorldEditReplace { from, to } => match key.as_str() {
+                "from" => parse_arg(from, arg),
+                "to" => parse_arg(to, arg),
+                _ => false,
+            },
+            Mechanic::Weather { duration, .. } => match key.as_str() {
+                "duration" => parse_arg(duration, arg),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
     pub fn get_desc(&self) -> impl Into<String> {
         match self {
             Mechanic::ActivateSpawner { spawner: _ } => {
@@ -2235,6 +4014,9 @@ impl Mechanic {
                 remove_delay: _,
                 can_pickup: _,
             } => "Fires a volley of arrows",
+            Mechanic::Aura { .. } => {
+                "Applies an aura to the target, running skills when it starts, ticks and ends"
+            }
             Mechanic::AuraRemove {
                 aura_name: _,
                 stacks: _,
@@ -2300,6 +4082,9 @@ impl Mechanic {
                 do_fake_looting: _,
                 items: _,
             } => "Breaks the block at the target location and gives an item/droptable",
+            Mechanic::CancelEvent => {
+                "Cancels the event that triggered the metaskill, ex: the damage of ~onDamaged"
+            }
             Mechanic::ClearExperience => "Clears the experience for the targeted players",
             Mechanic::ClearExperienceLevels => {
                 " Clears the experience levels for the targeted players"
@@ -2381,6 +4166,9 @@ impl Mechanic {
                 current_health: _,
             } => "Damages the target for a percent of their health",
             Mechanic::Decapitate => "Drops a player head item based on target",
+            Mechanic::Delay(_) => {
+                "Waits the given number of ticks before running the next mechanics of the metaskill"
+            }
             Mechanic::Doppleganger {
                 has_nameplate: _,
                 use_player_name: _,
@@ -2449,6 +4237,9 @@ impl Mechanic {
             } => "Creates a firework effect at the target",
             Mechanic::Flames => "Creates the flames effect at the location of the targeter",
             Mechanic::Fly => "Applies an aura that allows the targeted player to fly",
+            Mechanic::ForEach { .. } => {
+                "Runs a metaskill once for each of the targets, one at a time"
+            }
             Mechanic::ForcePull {
                 spread: _,
                 v_spread: _,
@@ -2558,6 +4349,9 @@ impl Mechanic {
                 message: _,
                 audience: _,
             } => "Sends a message to the target player(s)",
+            Mechanic::Missile { .. } => {
+                "Fires a homing projectile that turns towards the target while it travels"
+            }
             Mechanic::ModifyDamage {
                 amount: _,
                 damage_type: _,
@@ -2588,6 +4382,9 @@ impl Mechanic {
             Mechanic::MountTarget { entity: _ } => "Mounts the target",
             Mechanic::MovePin { x: _, y: _, z: _ } => "Moves the given pin to the target location",
             Mechanic::OpenTrades => "Opens the trades of the casting villager to the target player",
+            Mechanic::Orbital { .. } => {
+                "Makes projectiles orbit around the target, they run metaskills when they hit"
+            }
             Mechanic::Oxygen { amount: _ } => "Gives oxygen to a player target",
             Mechanic::Particle {
                 particle: _,
@@ -2708,6 +4505,9 @@ impl Mechanic {
             Mechanic::PrintParentTree => {
                 "Prints debug information regarding the Metaskill executing the mechanic and its SkillTree"
             }
+            Mechanic::Projectile { .. } => {
+                "Fires a custom projectile that runs metaskills while it travels and when it hits"
+            }
             Mechanic::Propel { velocity: _ } => "Propels the caster towards the target",
             Mechanic::Pull { velocity: _ } => "Pulls the target towards the mob",
             Mechanic::PushBlock { velocity: _ } => {
@@ -2719,6 +4519,9 @@ impl Mechanic {
                 "Executes a skill with the result of a raytrace to the target location"
             }
             Mechanic::Rally { radius: _ } => "Causes other nearby mobs to attack the target",
+            Mechanic::RandomSkill { .. } => {
+                "Runs one metaskill picked at random from a weighted list"
+            }
             Mechanic::RandomMessage { messages: _ } => {
                 "Sends a random message to the target player"
             }
@@ -2837,6 +4640,9 @@ impl Mechanic {
             } => "Sets the scoreboard value of a dummy player",
             Mechanic::SetSpeed { speed: _ } => "Sets the target entity's speed attribute",
             Mechanic::SetStance { stance: _ } => "Sets the stance of the target mob",
+            Mechanic::SetVariable { .. } => {
+                "Sets a variable of the given scope, the variable is created if it doesn't exist"
+            }
             Mechanic::Shield => "Applies an absorb shield to the target entity",
             Mechanic::ShieldBreak => {
                 "Forces the player to lower their shield and puts it on cooldown"
@@ -2844,6 +4650,7 @@ impl Mechanic {
             Mechanic::ShieldPercent { percent: _ } => {
                 "Applies an absorb shield to the target entity for a percentage of their max health"
             }
+            Mechanic::Shoot { .. } => "Shoots a vanilla projectile at the target",
             Mechanic::ShootFireball { velocity: _ } => "Shoots a fireball at the target",
             Mechanic::ShootPotion {
                 potion: _,
@@ -2855,6 +4662,7 @@ impl Mechanic {
             }
             Mechanic::ShowEntity { entity: _ } => "Shows the hidden caster to the targeted players",
             Mechanic::Signal { signal: _ } => "Sends a signal to a mob",
+            Mechanic::Skill { .. } => "Runs a metaskill",
             Mechanic::Skybox { skybox: _ } => "Alters the target player's skybox",
             Mechanic::Smoke => "Creates a puff of smoke",
             Mechanic::SmokeSwirl => "Creates a persistent \"swirl\" of smoke",
@@ -2890,6 +4698,9 @@ impl Mechanic {
             Mechanic::Stun { duration: _ } => "Stuns the target entity for a specified duration",
             Mechanic::StopUsingItem => "Stops the targeted entity from using an item",
             Mechanic::Suicide => "Causes the caster to die",
+            Mechanic::SudoSkill { .. } => {
+                "Makes the target run a metaskill as if it was the caster"
+            }
             Mechanic::Summon {
                 mob: _,
                 location: _,
@@ -2911,6 +4722,9 @@ impl Mechanic {
                 stack: _,
             } => "Summons a mob to ride the target.",
             Mechanic::Swap => "Swaps locations with the target",
+            Mechanic::Switch { .. } => {
+                "Runs the metaskill of the first case whose condition is met"
+            }
             Mechanic::SwingOffHand => "Makes the casting player swing their offhand",
             Mechanic::AddTag(_) => "Adds a scoreboard tag to the target",
             Mechanic::RemoveTag(_) => "Removes a scoreboard tag from the target",
@@ -2972,6 +4786,9 @@ impl Mechanic {
             Mechanic::ToggleSitting(_) => {
                 "Toggles the sitting state for cats, dogs, foxes, and parrots. Sets the sitting state"
             }
+            Mechanic::Totem { .. } => {
+                "Creates a static totem at the target location that runs metaskills on nearby entities"
+            }
             Mechanic::TotemOfUndying { model: _ } => {
                 "Plays the effect of a totem resurrecting a player with options to specify CustomModelData to use from resource packs."
             }
@@ -3037,6 +4854,7 @@ impl Mechanic {
                 remove_delay: _,
                 can_pickup: _,
             } => "Arrow Volley",
+            Mechanic::Aura { .. } => "Aura",
             Mechanic::AuraRemove {
                 aura_name: _,
                 stacks: _,
@@ -3098,6 +4916,7 @@ impl Mechanic {
                 do_fake_looting: _,
                 items: _,
             } => "BreakBlockAndGiveItem",
+            Mechanic::CancelEvent => "CancelEvent",
             Mechanic::ClearExperience => "ClearExperience",
             Mechanic::ClearExperienceLevels => "ClearExperienceLevels",
             Mechanic::GiveExperienceLevels { amount: _ } => "GiveExperienceLevels",
@@ -3163,6 +4982,7 @@ impl Mechanic {
                 current_health: _,
             } => "PercentDamage",
             Mechanic::Decapitate => "Decapitate",
+            Mechanic::Delay(_) => "Delay",
             Mechanic::Doppleganger {
                 has_nameplate: _,
                 use_player_name: _,
@@ -3225,6 +5045,7 @@ impl Mechanic {
             } => "Firework",
             Mechanic::Flames => "Flames",
             Mechanic::Fly => "Fly",
+            Mechanic::ForEach { .. } => "ForEach",
             Mechanic::ForcePull {
                 spread: _,
                 v_spread: _,
@@ -3324,6 +5145,7 @@ impl Mechanic {
                 message: _,
                 audience: _,
             } => "Message",
+            Mechanic::Missile { .. } => "Missile",
             Mechanic::ModifyDamage {
                 amount: _,
                 damage_type: _,
@@ -3354,6 +5176,7 @@ impl Mechanic {
             Mechanic::MountTarget { entity: _ } => "MountTarget",
             Mechanic::MovePin { x: _, y: _, z: _ } => "MovePin",
             Mechanic::OpenTrades => "OpenTrades",
+            Mechanic::Orbital { .. } => "Orbital",
             Mechanic::Oxygen { amount: _ } => "Oxygen",
             Mechanic::Particle {
                 particle: _,
@@ -3472,6 +5295,7 @@ impl Mechanic {
             Mechanic::PotionClear => "PotionClear",
             Mechanic::Prison => "Prison",
             Mechanic::PrintParentTree => "PrintParentTree",
+            Mechanic::Projectile { .. } => "Projectile",
             Mechanic::Propel { velocity: _ } => "Propel",
             Mechanic::Pull { velocity: _ } => "Pull",
             Mechanic::PushBlock { velocity: _ } => "PushBlock",
@@ -3480,6 +5304,7 @@ impl Mechanic {
             Mechanic::RayTraceTo => "RayTraceTo",
             Mechanic::Rally { radius: _ } => "Rally",
             Mechanic::RandomMessage { messages: _ } => "RandomMessage",
+            Mechanic::RandomSkill { .. } => "RandomSkill",
             Mechanic::Recoil { velocity: _ } => "Recoil",
             Mechanic::Remount => "Remount",
             Mechanic::Remove => "Remove",
@@ -3565,9 +5390,11 @@ impl Mechanic {
             } => "SetScore",
             Mechanic::SetSpeed { speed: _ } => "SetSpeed",
             Mechanic::SetStance { stance: _ } => "SetStance",
+            Mechanic::SetVariable { .. } => "SetVariable",
             Mechanic::Shield => "Shield",
             Mechanic::ShieldBreak => "ShieldBreak",
             Mechanic::ShieldPercent { percent: _ } => "ShieldPercent",
+            Mechanic::Shoot { .. } => "Shoot",
             Mechanic::ShootFireball { velocity: _ } => "ShootFireball",
             Mechanic::ShootPotion {
                 potion: _,
@@ -3577,6 +5404,7 @@ impl Mechanic {
             Mechanic::ShootShulkerBullet { velocity: _ } => "ShootShulkerBullet",
             Mechanic::ShowEntity { entity: _ } => "ShowEntity",
             Mechanic::Signal { signal: _ } => "Signal",
+            Mechanic::Skill { .. } => "Skill",
             Mechanic::Skybox { skybox: _ } => "Skybox",
             Mechanic::Smoke => "Smoke",
             Mechanic::SmokeSwirl => "SmokeSwirl",
@@ -3612,6 +5440,7 @@ impl Mechanic {
             Mechanic::Stun { duration: _ } => "Stun",
             Mechanic::StopUsingItem => "StopUsingItem",
             Mechanic::Suicide => "Suicide",
+            Mechanic::SudoSkill { .. } => "SudoSkill",
             Mechanic::Summon {
                 mob: _,
                 location: _,
@@ -3633,6 +5462,7 @@ impl Mechanic {
                 stack: _,
             } => "SummonPassenger",
             Mechanic::Swap => "Swap",
+            Mechanic::Switch { .. } => "Switch",
             Mechanic::SwingOffHand => "SwingOffHand",
             Mechanic::AddTag(_) => "AddTa",
             Mechanic::RemoveTag(_) => "RemoveTa",
@@ -3686,6 +5516,7 @@ impl Mechanic {
             } => "ToggleLever",
             Mechanic::TogglePiston => "TogglePiston",
             Mechanic::ToggleSitting(_) => "ToggleSittin",
+            Mechanic::Totem { .. } => "Totem",
             Mechanic::TotemOfUndying { model: _ } => "TotemOfUndying",
             Mechanic::TrackLocation => "TrackLocation",
             Mechanic::UndoPaste { paste_id: _ } => "UndoPaste",
@@ -3707,6 +5538,11 @@ impl Mechanic {
     }
 }
 
+/// Parse an argument into a field of the mechanic, false if it doesn't fit
+fn parse_arg<T: std::str::FromStr>(field: &mut T, arg: &str) -> bool {
+    arg.parse().map(|v| *field = v).is_ok()
+}
+
 impl Default for Mechanic {
     fn default() -> Self {
         Self::ActivateSpawner {
@@ -3747,7 +5583,8 @@ impl SpawnerSelect {
         }
     }
 
-    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+    /// `names` offers the spawners and spawner groups of the project
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, names: &ProjectNames) {
         ui.label("Spawner").on_hover_ui(|ui| {
             ui.label("The name of the spawner(s) to activate. This can accept groups and wildcards also using the appropriate syntax");
         });
@@ -3765,20 +5602,22 @@ impl SpawnerSelect {
                         ui.selectable_value(self, v.clone(), v.get_fields().into());
                     })
                 });
-            match self {
-                SpawnerSelect::SpawnerName(name) => {
-                    ui.label("Name");
-                    ui.text_edit_singleline(name)
-                }
-                SpawnerSelect::SpawnerGroup(name) => {
-                    ui.label("Group");
-                    ui.text_edit_singleline(name)
-                }
+            let (label, name, offered) = match self {
+                SpawnerSelect::SpawnerName(name) => ("Name", name, &names.spawners),
+                SpawnerSelect::SpawnerGroup(name) => ("Group", name, &names.spawner_groups),
                 SpawnerSelect::SpawnerIncrementName(name) => {
-                    ui.label("Incremented Name");
-                    ui.text_edit_singleline(name)
+                    ("Incremented Name", name, &names.spawners)
                 }
-            }
+            };
+            ui.label(label);
+            egui::ComboBox::new("spawner_select_name", "")
+                .selected_text(name.clone())
+                .show_ui(ui, |ui| {
+                    offered.iter().for_each(|v| {
+                        ui.selectable_value(name, v.clone(), v);
+                    });
+                });
+            ui.text_edit_singleline(name);
         });
     }
 }
@@ -3940,15 +5779,27 @@ impl EquipmentItem {
         self.slot = slot;
         self
     }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash) {
+        ui.add(egui::TextEdit::singleline(&mut self.item).hint_text("DIAMOND_SWORD"));
+        egui::ComboBox::new(("equipment_slot", id_salt), "")
+            .selected_text(self.slot.to_string())
+            .show_ui(ui, |ui| {
+                EquipmentSlot::iter().for_each(|slot| {
+                    let label = slot.to_string();
+                    ui.selectable_value(&mut self.slot, slot, label);
+                });
+            });
+    }
 }
 
 impl std::fmt::Display for EquipmentItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.item, self.slot)
+        write!(f, "{}:{}", self.item, self.slot)
     }
 }
 
-#[derive(Serialize, Deserialize, strum::Display, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, strum::Display, strum::EnumIter, Clone, PartialEq)]
 pub enum EquipmentSlot {
     #[strum(to_string = "HEAD")]
     ///The head slot. Accepts regular helmets, playerheads, and even blocktypes.
@@ -4037,6 +5888,172 @@ impl std::fmt::Display for AuraMechanic {
     }
 }
 
+/// A metaskill of a `randomskill` and its weight, written as `MetaSkill 0.3`
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct WeightedSkill {
+    pub skill: String,
+    pub weight: f32,
+}
+impl Default for WeightedSkill {
+    fn default() -> Self {
+        Self {
+            skill: String::new(),
+            weight: 1.0,
+        }
+    }
+}
+
+/// The weighted metaskills of a `randomskill`, written as `a 0.3,b 0.7`
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct WeightedSkills(pub Vec<WeightedSkill>);
+impl std::fmt::Display for WeightedSkills {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let skills: Vec<String> = self
+            .0
+            .iter()
+            .filter(|s| !s.skill.trim().is_empty())
+            .map(|s| format!("{} {}", s.skill.trim(), s.weight))
+            .collect();
+        write!(f, "{}", skills.join(","))
+    }
+}
+
+/// A case of a `switch`, its metaskill runs when the condition is met
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct SwitchCase {
+    pub condition: Condition,
+    pub skill: String,
+}
+
+/// The cases of a `switch`, written as `case isSneaking=skill{s=A} case DEFAULT=skill{s=B}`
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct SwitchCases {
+    pub cases: Vec<SwitchCase>,
+    /// Metaskill run when no condition is met, none when empty
+    pub default: String,
+}
+impl std::fmt::Display for SwitchCases {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut cases: Vec<String> = self
+            .cases
+            .iter()
+            .filter(|c| !c.skill.trim().is_empty())
+            .map(|c| {
+                format!(
+                    "case {}=skill{{s={}}}",
+                    c.condition.config(),
+                    c.skill.trim()
+                )
+            })
+            .collect();
+        if !self.default.trim().is_empty() {
+            cases.push(format!("case DEFAULT=skill{{s={}}}", self.default.trim()));
+        }
+        write!(f, "{}", cases.join(" "))
+    }
+}
+
+#[derive(Serialize, Deserialize, strum::Display, strum::EnumIter, Clone, Default, PartialEq)]
+pub enum VariableScope {
+    #[default]
+    #[strum(to_string = "caster")]
+    Caster,
+    #[strum(to_string = "target")]
+    Target,
+    #[strum(to_string = "skill")]
+    Skill,
+    #[strum(to_string = "world")]
+    World,
+    #[strum(to_string = "global")]
+    Global,
+}
+
+#[derive(Serialize, Deserialize, strum::Display, strum::EnumIter, Clone, Default, PartialEq)]
+pub enum VariableType {
+    #[default]
+    #[strum(to_string = "INTEGER")]
+    Integer,
+    #[strum(to_string = "FLOAT")]
+    Float,
+    #[strum(to_string = "DOUBLE")]
+    Double,
+    #[strum(to_string = "LONG")]
+    Long,
+    #[strum(to_string = "STRING")]
+    String,
+}
+
+/// Metaskills run by a projectile, written as `;onTick=MetaSkill`
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectileSkills {
+    pub on_start: MythicOption<String>,
+    pub on_tick: MythicOption<String>,
+    pub on_hit: MythicOption<String>,
+    pub on_end: MythicOption<String>,
+}
+impl std::fmt::Display for ProjectileSkills {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            self.on_start, self.on_tick, self.on_hit, self.on_end
+        )
+    }
+}
+
+/// Conditions checked on each entity before a projectile hits it
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct HitConditions(pub Vec<SkillCondition>);
+impl std::fmt::Display for HitConditions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let conditions: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, ";hitConditions=[ {} ]", conditions.join(" "))
+    }
+}
+
+#[derive(Serialize, Deserialize, strum::Display, strum::EnumIter, Clone, Default, PartialEq)]
+pub enum BulletType {
+    #[default]
+    #[strum(to_string = "ARROW")]
+    Arrow,
+    #[strum(to_string = "BLOCK")]
+    Block,
+    #[strum(to_string = "SMALLBLOCK")]
+    SmallBlock,
+    #[strum(to_string = "ITEM")]
+    Item,
+    #[strum(to_string = "MOB")]
+    Mob,
+    #[strum(to_string = "DISPLAY")]
+    Display,
+}
+
+#[derive(Serialize, Deserialize, strum::Display, strum::EnumIter, Clone, Default, PartialEq)]
+pub enum ShootType {
+    #[default]
+    #[strum(to_string = "ARROW")]
+    Arrow,
+    #[strum(to_string = "SPECTRAL_ARROW")]
+    SpectralArrow,
+    #[strum(to_string = "TRIDENT")]
+    Trident,
+    #[strum(to_string = "SNOWBALL")]
+    Snowball,
+    #[strum(to_string = "EGG")]
+    Egg,
+    #[strum(to_string = "ENDER_PEARL")]
+    EnderPearl,
+    #[strum(to_string = "FIREBALL")]
+    Fireball,
+    #[strum(to_string = "SMALL_FIREBALL")]
+    SmallFireball,
+    #[strum(to_string = "WITHER_SKULL")]
+    WitherSkull,
+}
+
 #[derive(Serialize, Deserialize, strum::Display, Clone, Default, PartialEq)]
 pub enum SpringType {
     #[strum(to_string = "water")]
@@ -4648,3 +6665,85 @@ impl RemoveAttribute {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picked(label: &str) -> Mechanic {
+        Mechanic::iter()
+            .find(|m| m.get_fields().into() == label)
+            .unwrap()
+            .with_defaults()
+    }
+
+    #[test]
+    fn projectiles_can_hit_when_picked() {
+        assert_eq!(
+            picked("Projectile").to_string(),
+            "- projectile{v=5;i=1;hR=2;vR=2;mr=40;g=0;syo=1;hp=true;hnp=false}"
+        );
+        for label in ["Missile", "Orbital", "Totem"] {
+            let rendered = picked(label).to_string();
+            assert!(
+                rendered.contains("hR=") && !rendered.contains("hR=0;"),
+                "{rendered}"
+            );
+            assert!(rendered.contains("hp=true"), "{rendered}");
+        }
+    }
+
+    #[test]
+    fn weighted_skills_and_cases_skip_blank_names() {
+        let skills = WeightedSkills(vec![
+            WeightedSkill {
+                skill: "a".into(),
+                weight: 0.3,
+            },
+            WeightedSkill::default(),
+            WeightedSkill {
+                skill: "b".into(),
+                weight: 0.7,
+            },
+        ]);
+        assert_eq!(
+            Mechanic::RandomSkill { skills }.to_string(),
+            "- randomskill{skills=a 0.3,b 0.7}"
+        );
+        let cases = SwitchCases {
+            cases: vec![
+                SwitchCase {
+                    condition: Condition::Burning,
+                    skill: "A".into(),
+                },
+                SwitchCase::default(),
+            ],
+            default: "B".into(),
+        };
+        assert_eq!(
+            Mechanic::Switch { cases }.to_string(),
+            "- switch{cases=case burning=skill{s=A} case DEFAULT=skill{s=B}}"
+        );
+    }
+
+    #[test]
+    fn metaskill_references_are_required() {
+        for label in ["Skill", "SudoSkill", "ForEach", "RandomSkill", "Switch"] {
+            assert!(picked(label).missing_value().is_some(), "{label}");
+        }
+        let skill = Mechanic::Skill { skill: "A".into() };
+        assert_eq!(skill.missing_value(), None);
+    }
+
+    #[test]
+    fn equip_writes_item_and_slot_as_one_argument() {
+        let item = EquipmentItem::default()
+            .add_item("DIAMOND_HELMET")
+            .select_slot(EquipmentSlot::Head);
+        let equip = Mechanic::Equip { item }.to_string();
+        assert_eq!(equip, "- equip{item=DIAMOND_HELMET:HEAD}");
+        let line = crate::skill::parser::parse_skill_line(&equip).unwrap();
+        assert_eq!(line.args.len(), 1);
+        assert_eq!(line.args[0].value.to_string(), "DIAMOND_HELMET:HEAD");
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, IntoStaticStr, VariantArray};
+
 #[derive(
     Debug,
     Serialize,
@@ -12,8 +13,6 @@ use strum::{Display, EnumString, IntoStaticStr, VariantArray};
     Clone,
 )]
 pub enum MinecraftMob {
-    #[strum(to_string = "MetaSkill")]
-    MetaSkill,
     #[strum(serialize = "AcaciaBoat", to_string = "AcaciaBoat")]
     AcaciaBoat,
     #[strum(serialize = "AcaciaChestBoat", to_string = "AcaciaChestBoat")]
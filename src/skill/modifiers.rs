@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+
+use super::ui::optional_number;
+
+/// Only run the skill when the health of the caster is in range, ex: `<50%`, `=30%-50%`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct HealthModifier {
+    pub range: HealthRange,
+    /// The values are percentages of the max health rather than health points
+    pub percent: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, strum::EnumIter)]
+pub enum HealthRange {
+    Below(f32),
+    Above(f32),
+    Between(f32, f32),
+}
+
+impl Default for HealthRange {
+    fn default() -> Self {
+        Self::Below(50.0)
+    }
+}
+
+impl HealthRange {
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            HealthRange::Below(_) => "Below",
+            HealthRange::Above(_) => "Above",
+            HealthRange::Between(_, _) => "Between",
+        }
+    }
+}
+
+impl std::fmt::Display for HealthModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = if self.percent { "%" } else { "" };
+        match self.range {
+            HealthRange::Below(value) => write!(f, "<{value}{unit}"),
+            HealthRange::Above(value) => write!(f, ">{value}{unit}"),
+            HealthRange::Between(min, max) => write!(f, "={min}{unit}-{max}{unit}"),
+        }
+    }
+}
+
+impl HealthModifier {
+    /// Read a health modifier as written in a skill line
+    pub fn parse(modifier: &str) -> Option<Self> {
+        let percent = modifier.contains('%');
+        let value = |s: &str| s.trim_end_matches('%').parse::<f32>().ok();
+        let range = match modifier.split_at_checked(1)? {
+            ("<", v) => HealthRange::Below(value(v)?),
+            (">", v) => HealthRange::Above(value(v)?),
+            ("=", v) => {
+                let (min, max) = v.split_once('-')?;
+                HealthRange::Between(value(min)?, value(max)?)
+            }
+            _ => return None,
+        };
+        Some(Self { range, percent })
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        egui::ComboBox::new("Health_Modifier", "")
+            .selected_text(self.range.get_fields().into())
+            .show_ui(ui, |ui| {
+                let value = match self.range {
+                    HealthRange::Below(v) | HealthRange::Above(v) | HealthRange::Between(v, _) => v,
+                };
+                [
+                    HealthRange::Below(value),
+                    HealthRange::Above(value),
+                    HealthRange::Between(value, value),
+                ]
+                .into_iter()
+                .for_each(|v| {
+                    let selected =
+                        std::mem::discriminant(&self.range) == std::mem::discriminant(&v);
+                    if ui
+                        .selectable_label(selected, v.get_fields().into())
+                        .clicked()
+                        && !selected
+                    {
+                        self.range = v;
+                    }
+                });
+            });
+        match &mut self.range {
+            HealthRange::Below(value) | HealthRange::Above(value) => {
+                ui.add(egui::DragValue::new(value));
+            }
+            HealthRange::Between(min, max) => {
+                ui.add(egui::DragValue::new(min));
+                ui.label("and");
+                ui.add(egui::DragValue::new(max));
+            }
+        }
+        ui.checkbox(&mut self.percent, "%").on_hover_ui(|ui| {
+            ui.label("The values are percentages of the max health");
+        });
+    }
+}
+
+/// Options every mechanic accepts, written with the mechanic arguments
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct MechanicOptions {
+    /// Ticks to wait before running the mechanic, `delay`
+    pub delay: Option<u32>,
+    /// How many more times the mechanic runs, `repeat`
+    pub repeat: Option<u32>,
+    /// Ticks between each repetition, `repeatInterval`
+    pub repeat_interval: Option<u32>,
+    /// Seconds before the mechanic can run again, `cooldown`
+    pub cooldown: Option<f32>,
+    /// Multiplier applied to the power of the mechanic, `power`
+    pub power: Option<f32>,
+    /// Run the mechanic from the origin of the skill instead of the caster, `fromOrigin`
+    pub from_origin: bool,
+}
+
+impl std::fmt::Display for MechanicOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut options: Vec<String> = Vec::new();
+        if let Some(delay) = self.delay {
+            options.push(format!("delay={delay}"));
+        }
+        if let Some(repeat) = self.repeat {
+            options.push(format!("repeat={repeat}"));
+        }
+        if let Some(repeat_interval) = self.repeat_interval {
+            options.push(format!("repeatInterval={repeat_interval}"));
+        }
+        if let Some(cooldown) = self.cooldown {
+            options.push(format!("cooldown={cooldown}"));
+        }
+        if let Some(power) = self.power {
+            options.push(format!("power={power}"));
+        }
+        if self.from_origin {
+            options.push("fromOrigin=true".to_owned());
+        }
+        write!(f, "{}", options.join(";"))
+    }
+}
+
+impl MechanicOptions {
+    /// Take the argument if it is one of these options, false if it belongs to the mechanic
+    pub fn set_arg(&mut self, key: &str, value: &str) -> bool {
+        match key.to_ascii_lowercase().as_str() {
+            "delay" => value.parse().map(|v| self.delay = Some(v)).is_ok(),
+            "repeat" => value.parse().map(|v| self.repeat = Some(v)).is_ok(),
+            "repeatinterval" | "repeati" => value
+                .parse()
+                .map(|v| self.repeat_interval = Some(v))
+                .is_ok(),
+            "cooldown" | "cd" => value.parse().map(|v| self.cooldown = Some(v)).is_ok(),
+            "power" => value.parse().map(|v| self.power = Some(v)).is_ok(),
+            "fromorigin" | "fo" => value.parse().map(|v| self.from_origin = v).is_ok(),
+            _ => false,
+        }
+    }
+
+    /// Insert the options in the argument braces of a rendered mechanic
+    pub fn apply(&self, mechanic: &str) -> String {
+        let options = self.to_string();
+        if options.is_empty() {
+            return mechanic.to_owned();
+        }
+        let name_start = mechanic.len() - mechanic.trim_start_matches(['-', ' ']).len();
+        let name_end = mechanic[name_start..]
+            .find(|c: char| c == '{' || c.is_whitespace())
+            .map_or(mechanic.len(), |i| name_start + i);
+        if !mechanic[name_end..].starts_with('{') {
+            return format!(
+                "{}{{{options}}}{}",
+                &mechanic[..name_end],
+                &mechanic[name_end..]
+            );
+        }
+        // Find the brace closing the arguments
+        let mut depth = 0;
+        let mut quote = None;
+        for (i, c) in mechanic[name_end..].char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '{' | '[') => depth += 1,
+                (None, '}' | ']') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let close = name_end + i;
+                        let separator = if close == name_end + 1 { "" } else { ";" };
+                        return format!(
+                            "{}{separator}{options}{}",
+                            &mechanic[..close],
+                            &mechanic[close..]
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        mechanic.to_owned()
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            optional_number(
+                ui,
+                "Delay",
+                "Ticks to wait before running the mechanic",
+                &mut self.delay,
+                1.0,
+            );
+            optional_number(
+                ui,
+                "Repeat",
+                "How many more times the mechanic runs",
+                &mut self.repeat,
+                1.0,
+            );
+            optional_number(
+                ui,
+                "Repeat Interval",
+                "Ticks between each repetition",
+                &mut self.repeat_interval,
+                1.0,
+            );
+        });
+        ui.horizontal(|ui| {
+            optional_number(
+                ui,
+                "Cooldown",
+                "Seconds before the mechanic can run again",
+                &mut self.cooldown,
+                1.0,
+            );
+            optional_number(
+                ui,
+                "Power",
+                "Multiplier applied to the power of the mechanic",
+                &mut self.power,
+                1.0,
+            );
+            ui.checkbox(&mut self.from_origin, "From Origin")
+                .on_hover_ui(|ui| {
+                    ui.label("Run the mechanic from the origin of the skill instead of the caster");
+                });
+        });
+    }
+}
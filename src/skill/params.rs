@@ -1 +1,169 @@
+use serde::{Deserialize, Serialize};
 
+/// A number or a range of numbers as written in conditions, ex: `5`, `>50`, `<10`, `1to5`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NumberRange {
+    Exact(f32),
+    Above(f32),
+    Below(f32),
+    Between(f32, f32),
+}
+
+impl Default for NumberRange {
+    fn default() -> Self {
+        Self::Above(0.0)
+    }
+}
+
+impl std::fmt::Display for NumberRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberRange::Exact(value) => write!(f, "{value}"),
+            NumberRange::Above(value) => write!(f, ">{value}"),
+            NumberRange::Below(value) => write!(f, "<{value}"),
+            NumberRange::Between(min, max) => write!(f, "{min}to{max}"),
+        }
+    }
+}
+
+impl NumberRange {
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            NumberRange::Exact(_) => "=",
+            NumberRange::Above(_) => ">",
+            NumberRange::Below(_) => "<",
+            NumberRange::Between(_, _) => "to",
+        }
+    }
+
+    /// The first bound of the range
+    fn value(&self) -> f32 {
+        match self {
+            NumberRange::Exact(value)
+            | NumberRange::Above(value)
+            | NumberRange::Below(value)
+            | NumberRange::Between(value, _) => *value,
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash) {
+        let value = self.value();
+        egui::ComboBox::new(("number_range", id_salt), "")
+            .width(40.0)
+            .selected_text(self.get_fields().into())
+            .show_ui(ui, |ui| {
+                [
+                    NumberRange::Exact(value),
+                    NumberRange::Above(value),
+                    NumberRange::Below(value),
+                    NumberRange::Between(value, value),
+                ]
+                .into_iter()
+                .for_each(|v| {
+                    let label = v.get_fields().into();
+                    let selected = std::mem::discriminant(self) == std::mem::discriminant(&v);
+                    if ui.selectable_label(selected, label).clicked() && !selected {
+                        *self = v;
+                    }
+                });
+            });
+        match self {
+            NumberRange::Exact(value) | NumberRange::Above(value) | NumberRange::Below(value) => {
+                ui.add(egui::DragValue::new(value).speed(0.1));
+            }
+            NumberRange::Between(min, max) => {
+                ui.add(egui::DragValue::new(min).speed(0.1));
+                ui.label("to");
+                ui.add(egui::DragValue::new(max).speed(0.1));
+            }
+        }
+    }
+}
+
+/// Vanilla biomes offered by the biome pickers, other biomes can still be typed
+const BIOMES: &[&str] = &[
+    "PLAINS",
+    "SUNFLOWER_PLAINS",
+    "FOREST",
+    "FLOWER_FOREST",
+    "BIRCH_FOREST",
+    "DARK_FOREST",
+    "TAIGA",
+    "SNOWY_TAIGA",
+    "SNOWY_PLAINS",
+    "ICE_SPIKES",
+    "JUNGLE",
+    "BAMBOO_JUNGLE",
+    "SAVANNA",
+    "DESERT",
+    "BADLANDS",
+    "SWAMP",
+    "MANGROVE_SWAMP",
+    "MEADOW",
+    "CHERRY_GROVE",
+    "WINDSWEPT_HILLS",
+    "STONY_PEAKS",
+    "BEACH",
+    "RIVER",
+    "OCEAN",
+    "DEEP_OCEAN",
+    "WARM_OCEAN",
+    "FROZEN_OCEAN",
+    "MUSHROOM_FIELDS",
+    "DRIPSTONE_CAVES",
+    "LUSH_CAVES",
+    "DEEP_DARK",
+    "NETHER_WASTES",
+    "SOUL_SAND_VALLEY",
+    "CRIMSON_FOREST",
+    "WARPED_FOREST",
+    "BASALT_DELTAS",
+    "THE_END",
+    "END_HIGHLANDS",
+];
+
+/// A list of biomes as written by the biome condition and random spawns, ex: `PLAINS,FOREST`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BiomeList(pub Vec<String>);
+
+impl std::fmt::Display for BiomeList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.biomes().collect::<Vec<_>>().join(","))
+    }
+}
+
+impl BiomeList {
+    /// The biomes of the list, without the blank ones
+    pub fn biomes(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|b| b.trim()).filter(|b| !b.is_empty())
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash) {
+        let id = egui::Id::new(id_salt);
+        let mut removed = None;
+        self.0.iter_mut().enumerate().for_each(|(i, biome)| {
+            egui::ComboBox::new(id.with(i), "")
+                .width(20.0)
+                .selected_text("")
+                .show_ui(ui, |ui| {
+                    BIOMES.iter().for_each(|b| {
+                        ui.selectable_value(biome, b.to_string(), *b);
+                    });
+                });
+            ui.add(egui::TextEdit::singleline(biome).desired_width(110.0));
+            if ui
+                .small_button("x")
+                .on_hover_text("Remove the biome")
+                .clicked()
+            {
+                removed = Some(i);
+            }
+        });
+        if let Some(i) = removed {
+            self.0.remove(i);
+        }
+        if ui.small_button("+").on_hover_text("Add a biome").clicked() {
+            self.0.push(BIOMES[0].to_owned());
+        }
+    }
+}
@@ -0,0 +1,645 @@
+use std::fmt::Display;
+
+/// A skill line such as
+/// `- damage{a=10;ia=true} @PlayersInRadius{r=10} ~onTimer:40 0.5`
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct SkillLine {
+    /// The mechanic name, with its `effect:` / `e:` prefix if any
+    pub mechanic: String,
+    /// The mechanic arguments, in the order they were written
+    pub args: Vec<Argument>,
+    /// The ticks of `delay 20`, the only mechanic written with a positional value
+    pub delay: Option<u32>,
+    pub targeter: Option<TargeterCall>,
+    /// Inline conditions, `?burning`, `?!day` or `?~isplayer`
+    pub conditions: Vec<ConditionCall>,
+    pub trigger: Option<TriggerCall>,
+    /// Health modifier such as `<50%`, `>200` or `=30%-50%`
+    pub health_modifier: Option<String>,
+    /// Chance for the skill to be executed, between 0 and 1
+    pub chance: Option<f32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Argument {
+    pub key: String,
+    pub value: ArgValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArgValue {
+    /// Unquoted value, `a=10`
+    Plain(String),
+    /// Quoted value, `m="Hello there"`, stored without its quotes
+    Quoted(char, String),
+    /// Inline skill list, `onTick=[ - particle{p=flame} - damage{a=1} ]`
+    SkillList(Vec<SkillLine>),
+}
+
+/// `@PlayersInRadius{r=10}`
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct TargeterCall {
+    /// Name without the `@`
+    pub name: String,
+    pub args: Vec<Argument>,
+}
+
+/// `?~!hasaura{aura=shield}`
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ConditionCall {
+    /// Checked against the trigger, `?~`
+    pub on_trigger: bool,
+    /// `?!`
+    pub negated: bool,
+    pub name: String,
+    pub args: Vec<Argument>,
+}
+
+/// `~onTimer:40`
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct TriggerCall {
+    /// Name without the `~`
+    pub name: String,
+    /// What follows the `:`
+    pub arg: Option<String>,
+}
+
+/// A syntax error and where it was found
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// Column of the error, starting at 1
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a single skill line, the leading `- ` is optional
+pub fn parse_skill_line(line: &str) -> Result<SkillLine, ParseError> {
+    let mut parser = Parser { src: line, pos: 0 };
+    parser.skip_whitespace();
+    if parser.peek() == Some('-') && parser.peek_nth(1).is_none_or(char::is_whitespace) {
+        parser.bump();
+    }
+    let skill = parser.skill_line(false)?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(skill),
+        Some(c) => Err(parser.error(format!("unexpected '{c}'"))),
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    /// Byte offset in src
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.src[self.pos..].chars().nth(n)
+    }
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        self.error_at(self.pos, message)
+    }
+    fn error_at(&self, pos: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            column: self.src[..pos].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+    /// Consume characters while `f` holds
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.bump();
+        }
+        &self.src[start..self.pos]
+    }
+    /// A name starts with a letter, a digit or `_`, `-damage` is not a mechanic
+    fn name(&mut self, what: &str) -> Result<String, ParseError> {
+        if let Some(c) = self.peek()
+            && !(c.is_alphanumeric() || c == '_')
+        {
+            return Err(self.error(format!("expected {what}, found '{c}'")));
+        }
+        let name = self.take_while(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '.' | '-'));
+        if name.is_empty() {
+            return Err(self.error(format!("expected {what}")));
+        }
+        Ok(name.to_owned())
+    }
+    /// A token that ends at a whitespace, or at the end of an inline skill list
+    fn word(&mut self, inline: bool) -> &str {
+        self.take_while(|c| !(c.is_whitespace() || inline && c == ']'))
+    }
+
+    fn skill_line(&mut self, inline: bool) -> Result<SkillLine, ParseError> {
+        self.skip_whitespace();
+        let mut skill = SkillLine {
+            mechanic: self.name("a mechanic name")?,
+            ..Default::default()
+        };
+        // A delay takes nothing but its ticks
+        if skill.mechanic.eq_ignore_ascii_case("delay") {
+            self.skip_whitespace();
+            let start = self.pos;
+            let ticks = self.word(inline).to_owned();
+            let ticks = ticks.parse().map_err(|_| {
+                self.error_at(
+                    start,
+                    format!("invalid delay '{ticks}', expected a number of ticks"),
+                )
+            })?;
+            skill.delay = Some(ticks);
+            return Ok(skill);
+        }
+        if self.peek() == Some('{') {
+            skill.args = self.arguments()?;
+        }
+        loop {
+            self.skip_whitespace();
+            let start = self.pos;
+            match self.peek() {
+                None => break,
+                Some(']') if inline => break,
+                Some('-') if inline && self.peek_nth(1).is_none_or(char::is_whitespace) => break,
+                Some('@') => {
+                    if skill.targeter.is_some() {
+                        return Err(self.error("a skill can only have one targeter"));
+                    }
+                    self.bump();
+                    let mut targeter = TargeterCall {
+                        name: self.name("a targeter name")?,
+                        ..Default::default()
+                    };
+                    if self.peek() == Some('{') {
+                        targeter.args = self.arguments()?;
+                    }
+                    skill.targeter = Some(targeter);
+                }
+                Some('?') => {
+                    self.bump();
+                    let mut condition = ConditionCall {
+                        on_trigger: self.eat('~'),
+                        negated: self.eat('!'),
+                        name: self.name("a condition name")?,
+                        ..Default::default()
+                    };
+                    if self.peek() == Some('{') {
+                        condition.args = self.arguments()?;
+                    }
+                    skill.conditions.push(condition);
+                }
+                Some('~') => {
+                    if skill.trigger.is_some() {
+                        return Err(self.error("a skill can only have one trigger"));
+                    }
+                    self.bump();
+                    let name = self.take_while(char::is_alphanumeric).to_owned();
+                    if name.is_empty() {
+                        return Err(self.error("expected a trigger name"));
+                    }
+                    let mut trigger = TriggerCall { name, arg: None };
+                    if self.eat(':') {
+                        let arg = self.word(inline).to_owned();
+                        if arg.is_empty() {
+                            return Err(self.error("expected a trigger argument after ':'"));
+                        }
+                        trigger.arg = Some(arg);
+                    }
+                    skill.trigger = Some(trigger);
+                }
+                Some('<' | '>' | '=') => {
+                    if skill.health_modifier.is_some() {
+                        return Err(self.error("a skill can only have one health modifier"));
+                    }
+                    let modifier = self.word(inline).to_owned();
+                    if modifier.len() == 1 {
+                        return Err(self.error("expected a value after the health modifier"));
+                    }
+                    skill.health_modifier = Some(modifier);
+                }
+                Some(c) if c.is_ascii_digit() || c == '.' => {
+                    if skill.chance.is_some() {
+                        return Err(self.error("a skill can only have one chance"));
+                    }
+                    let chance = self.word(inline).to_owned();
+                    match chance.parse::<f32>() {
+                        Ok(chance) if (0.0..=1.0).contains(&chance) => skill.chance = Some(chance),
+                        _ => {
+                            return Err(self.error_at(
+                                start,
+                                format!(
+                                    "invalid chance '{chance}', expected a number between 0 and 1"
+                                ),
+                            ));
+                        }
+                    }
+                }
+                Some(c) => return Err(self.error(format!("unexpected '{c}'"))),
+            }
+        }
+        Ok(skill)
+    }
+
+    /// `{key=value;key=value}`
+    fn arguments(&mut self) -> Result<Vec<Argument>, ParseError> {
+        let open = self.pos;
+        self.bump();
+        let mut args = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                return Ok(args);
+            }
+            if self.peek().is_none() {
+                return Err(self.error_at(open, "unclosed '{'"));
+            }
+            let key = self
+                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-'))
+                .to_owned();
+            if key.is_empty() {
+                return Err(self.error("expected an argument name"));
+            }
+            self.skip_whitespace();
+            if !self.eat('=') {
+                return Err(self.error(format!("expected '=' after '{key}'")));
+            }
+            self.skip_whitespace();
+            let value = self.value(&key)?;
+            args.push(Argument { key, value });
+            self.skip_whitespace();
+            match self.peek() {
+                Some(';') => {
+                    self.bump();
+                }
+                Some('}') => {}
+                None => return Err(self.error_at(open, "unclosed '{'")),
+                Some(c) => return Err(self.error(format!("expected ';' or '}}', found '{c}'"))),
+            }
+        }
+    }
+
+    fn value(&mut self, key: &str) -> Result<ArgValue, ParseError> {
+        let start = self.pos;
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                let content = self.take_while(|c| c != quote).to_owned();
+                if !self.eat(quote) {
+                    return Err(self.error_at(start, "unclosed quote"));
+                }
+                Ok(ArgValue::Quoted(quote, content))
+            }
+            Some('[') => {
+                self.bump();
+                let mut skills = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.eat(']') {
+                        return Ok(ArgValue::SkillList(skills));
+                    }
+                    if self.peek().is_none() {
+                        return Err(self.error_at(start, "unclosed '['"));
+                    }
+                    if !self.eat('-') {
+                        return Err(self.error("expected '-' before an inline skill"));
+                    }
+                    skills.push(self.skill_line(true)?);
+                }
+            }
+            _ => {
+                let mut depth = 0;
+                while let Some(c) = self.peek() {
+                    match c {
+                        ';' | '}' if depth == 0 => break,
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    self.bump();
+                }
+                let value = self.src[start..self.pos].trim_end();
+                if value.is_empty() {
+                    return Err(self.error_at(start, format!("missing value for '{key}'")));
+                }
+                Ok(ArgValue::Plain(value.to_owned()))
+            }
+        }
+    }
+}
+
+impl Display for SkillLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mechanic)?;
+        write_args(f, &self.args)?;
+        if let Some(delay) = self.delay {
+            write!(f, " {delay}")?;
+        }
+        if let Some(targeter) = &self.targeter {
+            write!(f, " {targeter}")?;
+        }
+        for condition in &self.conditions {
+            write!(f, " {condition}")?;
+        }
+        if let Some(trigger) = &self.trigger {
+            write!(f, " {trigger}")?;
+        }
+        if let Some(modifier) = &self.health_modifier {
+            write!(f, " {modifier}")?;
+        }
+        if let Some(chance) = self.chance {
+            write!(f, " {chance}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Remove the arguments with an empty value from a rendered `name{a=;b=1}`, so that the
+/// plugin uses its default value instead of failing on the missing value
+pub fn omit_empty_args(rendered: &str) -> String {
+    let Some(open) = rendered.find('{') else {
+        return rendered.to_owned();
+    };
+    let Some(body) = rendered[open + 1..].strip_suffix('}') else {
+        return rendered.to_owned();
+    };
+    let mut args = Vec::new();
+    let (mut depth, mut quote, mut start) = (0, None, 0);
+    for (i, c) in body.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '{' | '[') => depth += 1,
+            (None, '}' | ']') => depth -= 1,
+            (None, ';') if depth == 0 => {
+                args.push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(&body[start..]);
+    let args: Vec<&str> = args
+        .into_iter()
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty() && !arg.ends_with('='))
+        .collect();
+    if args.is_empty() {
+        return rendered[..open].to_owned();
+    }
+    format!("{}{{{}}}", &rendered[..open], args.join(";"))
+}
+
+fn write_args(f: &mut std::fmt::Formatter<'_>, args: &[Argument]) -> std::fmt::Result {
+    if args.is_empty() {
+        return Ok(());
+    }
+    write!(f, "{{")?;
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            write!(f, ";")?;
+        }
+        write!(f, "{arg}")?;
+    }
+    write!(f, "}}")
+}
+
+impl Display for Argument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+impl Display for ArgValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgValue::Plain(value) => write!(f, "{value}"),
+            ArgValue::Quoted(quote, value) => write!(f, "{quote}{value}{quote}"),
+            ArgValue::SkillList(skills) => {
+                write!(f, "[")?;
+                for skill in skills {
+                    write!(f, " - {skill}")?;
+                }
+                write!(f, " ]")
+            }
+        }
+    }
+}
+
+impl Display for TargeterCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}", self.name)?;
+        write_args(f, &self.args)
+    }
+}
+
+impl Display for ConditionCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "?")?;
+        if self.on_trigger {
+            write!(f, "~")?;
+        }
+        if self.negated {
+            write!(f, "!")?;
+        }
+        write!(f, "{}", self.name)?;
+        write_args(f, &self.args)
+    }
+}
+
+impl Display for TriggerCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "~{}", self.name)?;
+        if let Some(arg) = &self.arg {
+            write!(f, ":{arg}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(key: &str, value: &str) -> Argument {
+        Argument {
+            key: key.to_owned(),
+            value: ArgValue::Plain(value.to_owned()),
+        }
+    }
+
+    fn column(line: &str) -> usize {
+        parse_skill_line(line).unwrap_err().column
+    }
+
+    #[test]
+    fn parses_full_line() {
+        let line =
+            parse_skill_line("- damage{a=10;ia=true} @PlayersInRadius{r=10} ~onTimer:40 0.5")
+                .unwrap();
+        assert_eq!(line.mechanic, "damage");
+        assert_eq!(line.args, vec![plain("a", "10"), plain("ia", "true")]);
+        assert_eq!(
+            line.targeter,
+            Some(TargeterCall {
+                name: "PlayersInRadius".to_owned(),
+                args: vec![plain("r", "10")],
+            })
+        );
+        assert_eq!(
+            line.trigger,
+            Some(TriggerCall {
+                name: "onTimer".to_owned(),
+                arg: Some("40".to_owned()),
+            })
+        );
+        assert_eq!(line.health_modifier, None);
+        assert_eq!(line.chance, Some(0.5));
+    }
+
+    #[test]
+    fn parses_health_modifier_and_conditions() {
+        let line = parse_skill_line("heal{a=5} @self ?~!burning <50% 0.2").unwrap();
+        assert_eq!(line.health_modifier.as_deref(), Some("<50%"));
+        assert_eq!(line.chance, Some(0.2));
+        assert_eq!(
+            line.conditions,
+            vec![ConditionCall {
+                on_trigger: true,
+                negated: true,
+                name: "burning".to_owned(),
+                args: Vec::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_nested_skill_lists() {
+        let line =
+            parse_skill_line("- aura{onTick=[ - a{x=1} - b{y=[ - c{} ]} @self ];d=20}").unwrap();
+        assert_eq!(line.args.len(), 2);
+        assert_eq!(line.args[1], plain("d", "20"));
+        let ArgValue::SkillList(skills) = &line.args[0].value else {
+            panic!("onTick should be a skill list");
+        };
+        assert_eq!(skills.len(), 2);
+        assert_eq!(skills[0].mechanic, "a");
+        assert_eq!(skills[0].args, vec![plain("x", "1")]);
+        assert_eq!(skills[1].mechanic, "b");
+        assert_eq!(skills[1].targeter.as_ref().unwrap().name, "self");
+        let ArgValue::SkillList(inner) = &skills[1].args[0].value else {
+            panic!("y should be a skill list");
+        };
+        assert_eq!(inner[0].mechanic, "c");
+    }
+
+    #[test]
+    fn parses_quoted_strings() {
+        let line = parse_skill_line(r#"message{m="Hello; {there}";n='a "b"'}"#).unwrap();
+        assert_eq!(
+            line.args,
+            vec![
+                Argument {
+                    key: "m".to_owned(),
+                    value: ArgValue::Quoted('"', "Hello; {there}".to_owned()),
+                },
+                Argument {
+                    key: "n".to_owned(),
+                    value: ArgValue::Quoted('\'', "a \"b\"".to_owned()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let src = r#"damage{a=10;m="x y";t=[ - a{} - b{c=1} ]} @self ~onTimer:40 0.5"#;
+        let line = parse_skill_line(src).unwrap();
+        assert_eq!(parse_skill_line(&line.to_string()).unwrap(), line);
+    }
+
+    #[test]
+    fn parses_delay_ticks() {
+        let line = parse_skill_line("- delay 20").unwrap();
+        assert_eq!(line.mechanic, "delay");
+        assert_eq!(line.delay, Some(20));
+        assert_eq!(line.to_string(), "delay 20");
+        let line = parse_skill_line("- aura{onTick=[ - delay 5 - a{x=1} ]}").unwrap();
+        let ArgValue::SkillList(skills) = &line.args[0].value else {
+            panic!("onTick should be a skill list");
+        };
+        assert_eq!(skills[0].delay, Some(5));
+        assert_eq!(skills[1].mechanic, "a");
+        assert_eq!(column("delay"), 6);
+        assert_eq!(column("delay 0.5"), 7);
+        assert_eq!(column("delay 20 @self"), 10);
+    }
+
+    #[test]
+    fn reports_error_columns() {
+        assert_eq!(column("damage{a=10"), 7);
+        assert_eq!(column("damage{a}"), 9);
+        assert_eq!(column("damage{a=}"), 10);
+        assert_eq!(column("damage{=1}"), 8);
+        assert_eq!(column(r#"message{m="hi}"#), 11);
+        assert_eq!(column("damage{a=1} @"), 14);
+        assert_eq!(column("damage ~onTimer:"), 17);
+        assert_eq!(column("damage 1.5"), 8);
+        assert_eq!(column("damage @self @self"), 14);
+        assert_eq!(column("aura{t=[ - a{} "), 8);
+        assert_eq!(column("aura{t=[ a{} ]}"), 10);
+    }
+
+    #[test]
+    fn omits_empty_arguments() {
+        assert_eq!(
+            omit_empty_args("biome{b=;exact=false}"),
+            "biome{exact=false}"
+        );
+        assert_eq!(omit_empty_args("haspotioneffect{t=;l=}"), "haspotioneffect");
+        assert_eq!(
+            omit_empty_args(r#"stringequals{v1="a;b=";v2=}"#),
+            r#"stringequals{v1="a;b="}"#
+        );
+        assert_eq!(omit_empty_args("burning"), "burning");
+    }
+
+    #[test]
+    fn rejects_names_starting_with_a_dash() {
+        let error = parse_skill_line("-damage{a=1}").unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.message, "expected a mechanic name, found '-'");
+        assert_eq!(column("damage @-self"), 9);
+        assert_eq!(column("damage ?-burning"), 9);
+    }
+}
@@ -1,12 +1,20 @@
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::ProjectNames;
+use super::ui::{optional_number, optional_value};
+use super::{conditions::SkillCondition, mechanics::SpawnerSelect};
 
 #[derive(strum::Display, Clone, Serialize, Deserialize, PartialEq, strum::EnumIter)]
 pub enum Targeter {
+    #[strum(to_string = "{0}{1}")]
+    SingleEntity(SingleEntityTarget, TargeterOptions),
+    #[strum(to_string = "{0}{1}")]
+    MultyEntity(MultyEntity, TargeterOptions),
+    #[strum(to_string = "{0}{1}")]
+    ThreatTable(ThreatTableTarget, TargeterOptions),
     #[strum(to_string = "{0}")]
-    SingleEntity(SingleEntityTarget),
-    #[strum(to_string = "{0}")]
-    MultyEntity(MultyEntity),
-    ThreatTable,
+    Location(LocationTarget),
     #[strum(to_string = "")]
     None,
 }
@@ -20,9 +28,10 @@ impl Default for Targeter {
 impl Targeter {
     pub fn get_fields(&self) -> impl Into<String> {
         match self {
-            Targeter::SingleEntity(single_entity_target) => "Single Entity",
-            Targeter::MultyEntity(multy_entity) => "Multi Entity",
-            Targeter::ThreatTable => "Threat Table",
+            Targeter::SingleEntity(_, _) => "Single Entity",
+            Targeter::MultyEntity(_, _) => "Multi Entity",
+            Targeter::ThreatTable(_, _) => "Threat Table",
+            Targeter::Location(_) => "Location",
             Targeter::None => "None",
         }
     }
@@ -30,13 +39,25 @@ impl Targeter {
 
 impl From<SingleEntityTarget> for Targeter {
     fn from(value: SingleEntityTarget) -> Self {
-        Self::SingleEntity(value)
+        Self::SingleEntity(value, TargeterOptions::default())
+    }
+}
+
+impl From<ThreatTableTarget> for Targeter {
+    fn from(value: ThreatTableTarget) -> Self {
+        Self::ThreatTable(value, TargeterOptions::default())
+    }
+}
+
+impl From<LocationTarget> for Targeter {
+    fn from(value: LocationTarget) -> Self {
+        Self::Location(value)
     }
 }
 
 impl From<MultyEntity> for Targeter {
     fn from(value: MultyEntity) -> Self {
-        Self::MultyEntity(value)
+        Self::MultyEntity(value, TargeterOptions::default())
     }
 }
 
@@ -213,3 +234,516 @@ pub enum MultyEntity {
     ///Targets all item drops in the given radius
     ItemsInRadius,
 }
+
+impl SingleEntityTarget {
+    /// The options this targeter accepts
+    pub fn options(&self) -> &'static [TargeterOption] {
+        match self {
+            SingleEntityTarget::NearestPlayer => &[TargeterOption::Radius],
+            _ => &[],
+        }
+    }
+}
+
+impl MultyEntity {
+    /// The options this targeter accepts
+    pub fn options(&self) -> &'static [TargeterOption] {
+        use TargeterOption::*;
+        match self {
+            MultyEntity::LivingInCone => &[Angle, Range, Rotation, Limit, Sort, Ignore, Conditions],
+            MultyEntity::NotLivingNearOrigin
+            | MultyEntity::PlayersInRadius
+            | MultyEntity::EntitiesInRadius
+            | MultyEntity::PlayersNearOrigin
+            | MultyEntity::EntitiesNearOrigin
+            | MultyEntity::ItemsNearOrigin
+            | MultyEntity::ItemsInRadius => &[Radius, Limit, Sort, Ignore, Conditions],
+            MultyEntity::MobsInRadius | MultyEntity::MobsNearOrigin => {
+                &[Radius, Types, Limit, Sort, Ignore, Conditions]
+            }
+            MultyEntity::EntitiesInRing
+            | MultyEntity::EntitiesInRingNearOrigin
+            | MultyEntity::PlayersInRing => {
+                &[MinRadius, MaxRadius, Limit, Sort, Ignore, Conditions]
+            }
+            MultyEntity::LivingInWorld
+            | MultyEntity::PlayerInWorld
+            | MultyEntity::PlayersOnServer
+            | MultyEntity::TrackedPlayers
+            | MultyEntity::Children
+            | MultyEntity::Siblings => &[Limit, Sort, Ignore, Conditions],
+        }
+    }
+}
+
+/// Targeters picking entities from the threat table of the caster
+#[derive(
+    strum::Display, Clone, Serialize, Deserialize, strum::VariantArray, PartialEq, Default,
+)]
+pub enum ThreatTableTarget {
+    #[strum(to_string = "@ThreatTable")]
+    #[default]
+    ///Targets every entity in the caster's threat table
+    ThreatTable,
+
+    #[strum(to_string = "@ThreatTablePlayers")]
+    ///Targets every player in the caster's threat table
+    ThreatTablePlayers,
+
+    #[strum(to_string = "@RandomThreatTarget")]
+    ///Targets a random entity in the caster's threat table
+    RandomThreatTarget,
+}
+
+impl ThreatTableTarget {
+    /// The options this targeter accepts
+    pub fn options(&self) -> &'static [TargeterOption] {
+        match self {
+            ThreatTableTarget::ThreatTable | ThreatTableTarget::ThreatTablePlayers => &[
+                TargeterOption::Limit,
+                TargeterOption::Sort,
+                TargeterOption::Conditions,
+            ],
+            ThreatTableTarget::RandomThreatTarget => &[TargeterOption::Conditions],
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, strum::Display, strum::EnumIter, Default)]
+pub enum LocationTarget {
+    #[strum(to_string = "@Origin")]
+    #[default]
+    ///Targets the origin of the meta-skill, or the caster's location when there is none
+    Origin,
+
+    #[strum(to_string = "@SelfLocation")]
+    ///Targets the caster's location
+    SelfLocation,
+
+    #[strum(to_string = "@TargetLocation")]
+    ///Targets the location of the caster's target
+    TargetLocation,
+
+    #[strum(to_string = "@Ring{{radius={radius};points={points}}}")]
+    ///Targets points in a ring around the caster
+    Ring {
+        /// The radius of the ring
+        radius: f32,
+        /// The amount of points on the ring
+        points: u32,
+    },
+
+    #[strum(to_string = "@Cone{{angle={angle};range={range};points={points};rotation={rotation}}}")]
+    ///Targets random points in a cone in front of the caster
+    Cone {
+        /// The angle of the cone
+        angle: f32,
+        /// The length of the cone
+        range: f32,
+        /// The amount of points in the cone
+        points: u32,
+        /// The rotation of the cone relative to the facing direction
+        rotation: f32,
+    },
+
+    #[strum(to_string = "@Spawners{{spawners={spawners}}}")]
+    ///Targets the location of MythicMobs spawners
+    Spawners {
+        /// The spawner(s) to target
+        spawners: SpawnerSelect,
+    },
+
+    #[strum(
+        to_string = "@RandomLocationsNearCaster{{amount={amount};radius={radius};minradius={min_radius}}}"
+    )]
+    ///Targets random locations around the caster
+    RandomLocationsNearCaster {
+        /// The amount of locations
+        amount: u32,
+        /// The maximum distance from the caster
+        radius: f32,
+        /// The minimum distance from the caster
+        min_radius: f32,
+    },
+
+    #[strum(to_string = "@Location{{c={x},{y},{z}}}")]
+    ///Targets the given coordinates in the caster's world
+    Location { x: f32, y: f32, z: f32 },
+
+    #[strum(to_string = "@BlocksInRadius{{r={radius}}}")]
+    ///Targets every block in the given radius
+    BlocksInRadius {
+        /// The radius to target blocks in
+        radius: f32,
+    },
+
+    #[strum(to_string = "@ForwardWall{{forward={forward};width={width};height={height}}}")]
+    ///Targets a wall of locations in front of the caster
+    ForwardWall {
+        /// How far in front of the caster the wall is
+        forward: f32,
+        /// The width of the wall
+        width: f32,
+        /// The height of the wall
+        height: f32,
+    },
+}
+
+impl LocationTarget {
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            LocationTarget::Origin => "Origin",
+            LocationTarget::SelfLocation => "Self Location",
+            LocationTarget::TargetLocation => "Target Location",
+            LocationTarget::Ring { .. } => "Ring",
+            LocationTarget::Cone { .. } => "Cone",
+            LocationTarget::Spawners { .. } => "Spawners",
+            LocationTarget::RandomLocationsNearCaster { .. } => "Random Locations Near Caster",
+            LocationTarget::Location { .. } => "Location",
+            LocationTarget::BlocksInRadius { .. } => "Blocks In Radius",
+            LocationTarget::ForwardWall { .. } => "Forward Wall",
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, names: &ProjectNames) {
+        ui.horizontal(|ui| {
+            ui.label("Location:");
+            egui::ComboBox::new("Location_Target_Dropdown_Menu", "")
+                .selected_text(self.get_fields().into())
+                .show_ui(ui, |ui| {
+                    LocationTarget::iter().for_each(|v| {
+                        let selected = std::mem::discriminant(self) == std::mem::discriminant(&v);
+                        if ui
+                            .selectable_label(selected, v.get_fields().into())
+                            .clicked()
+                            && !selected
+                        {
+                            *self = v;
+                        }
+                    });
+                });
+        });
+        ui.horizontal(|ui| match self {
+            LocationTarget::Origin
+            | LocationTarget::SelfLocation
+            | LocationTarget::TargetLocation => {}
+            LocationTarget::Ring { radius, points } => {
+                ui.label("Radius");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+                ui.label("Points");
+                ui.add(egui::DragValue::new(points));
+            }
+            LocationTarget::Cone {
+                angle,
+                range,
+                points,
+                rotation,
+            } => {
+                ui.label("Angle");
+                ui.add(egui::DragValue::new(angle).speed(0.1));
+                ui.label("Range");
+                ui.add(egui::DragValue::new(range).speed(0.1));
+                ui.label("Points");
+                ui.add(egui::DragValue::new(points));
+                ui.label("Rotation");
+                ui.add(egui::DragValue::new(rotation).speed(0.1));
+            }
+            LocationTarget::Spawners { spawners } => spawners.handle_ui(ui, names),
+            LocationTarget::RandomLocationsNearCaster {
+                amount,
+                radius,
+                min_radius,
+            } => {
+                ui.label("Amount");
+                ui.add(egui::DragValue::new(amount));
+                ui.label("Radius");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+                ui.label("Min Radius");
+                ui.add(egui::DragValue::new(min_radius).speed(0.1));
+            }
+            LocationTarget::Location { x, y, z } => {
+                ui.label("X");
+                ui.add(egui::DragValue::new(x).speed(0.1));
+                ui.label("Y");
+                ui.add(egui::DragValue::new(y).speed(0.1));
+                ui.label("Z");
+                ui.add(egui::DragValue::new(z).speed(0.1));
+            }
+            LocationTarget::BlocksInRadius { radius } => {
+                ui.label("Radius");
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+            }
+            LocationTarget::ForwardWall {
+                forward,
+                width,
+                height,
+            } => {
+                ui.label("Forward");
+                ui.add(egui::DragValue::new(forward).speed(0.1));
+                ui.label("Width");
+                ui.add(egui::DragValue::new(width).speed(0.1));
+                ui.label("Height");
+                ui.add(egui::DragValue::new(height).speed(0.1));
+            }
+        });
+    }
+}
+
+/// An option written between braces after a targeter
+#[derive(Clone, Copy, PartialEq)]
+pub enum TargeterOption {
+    Radius,
+    MinRadius,
+    MaxRadius,
+    Angle,
+    Range,
+    Rotation,
+    Types,
+    Limit,
+    Sort,
+    Ignore,
+    Conditions,
+}
+
+/// The options of a targeter, ex: `@PlayersInRadius{r=10;limit=2;sort=NEAREST}`.
+/// Options left to None are not written
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TargeterOptions {
+    /// The radius to target entities in, `r`
+    pub radius: Option<f32>,
+    /// The inner radius of a ring, `minr`
+    pub min_radius: Option<f32>,
+    /// The outer radius of a ring, `maxr`
+    pub max_radius: Option<f32>,
+    /// The angle of a cone, `angle`
+    pub angle: Option<f32>,
+    /// The length of a cone, `range`
+    pub range: Option<f32>,
+    /// The rotation of a cone relative to the facing direction, `rotation`
+    pub rotation: Option<f32>,
+    /// A list of mob types, `types`
+    pub types: Option<String>,
+    /// The maximum amount of targets, `limit`
+    pub limit: Option<u32>,
+    /// How targets are ordered before the limit is applied, `sort`
+    pub sort: Option<TargetSort>,
+    /// A list of entity types to leave out, `ignore`
+    pub ignore: Option<String>,
+    /// Conditions every target has to meet, `conditions`
+    pub conditions: Vec<SkillCondition>,
+}
+
+impl std::fmt::Display for TargeterOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut options: Vec<String> = Vec::new();
+        let numbers = [
+            ("r", self.radius),
+            ("minr", self.min_radius),
+            ("maxr", self.max_radius),
+            ("angle", self.angle),
+            ("range", self.range),
+            ("rotation", self.rotation),
+        ];
+        numbers.iter().for_each(|(key, value)| {
+            if let Some(value) = value {
+                options.push(format!("{key}={value}"));
+            }
+        });
+        if let Some(types) = &self.types {
+            options.push(format!("types={types}"));
+        }
+        if let Some(limit) = self.limit {
+            options.push(format!("limit={limit}"));
+        }
+        if let Some(sort) = &self.sort {
+            options.push(format!("sort={sort}"));
+        }
+        if let Some(ignore) = &self.ignore {
+            options.push(format!("ignore={ignore}"));
+        }
+        if !self.conditions.is_empty() {
+            let conditions: Vec<String> = self.conditions.iter().map(|c| c.to_string()).collect();
+            options.push(format!("conditions=[ {} ]", conditions.join(" ")));
+        }
+        if options.is_empty() {
+            return Ok(());
+        }
+        write!(f, "{{{}}}", options.join(";"))
+    }
+}
+
+impl TargeterOptions {
+    /// Unset the options the targeter doesn't accept
+    pub fn retain(&mut self, accepted: &[TargeterOption]) {
+        let kept = |option: TargeterOption| accepted.contains(&option);
+        if !kept(TargeterOption::Radius) {
+            self.radius = None;
+        }
+        if !kept(TargeterOption::MinRadius) {
+            self.min_radius = None;
+        }
+        if !kept(TargeterOption::MaxRadius) {
+            self.max_radius = None;
+        }
+        if !kept(TargeterOption::Angle) {
+            self.angle = None;
+        }
+        if !kept(TargeterOption::Range) {
+            self.range = None;
+        }
+        if !kept(TargeterOption::Rotation) {
+            self.rotation = None;
+        }
+        if !kept(TargeterOption::Types) {
+            self.types = None;
+        }
+        if !kept(TargeterOption::Limit) {
+            self.limit = None;
+        }
+        if !kept(TargeterOption::Sort) {
+            self.sort = None;
+        }
+        if !kept(TargeterOption::Ignore) {
+            self.ignore = None;
+        }
+        if !kept(TargeterOption::Conditions) {
+            self.conditions.clear();
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, accepted: &[TargeterOption]) {
+        accepted.iter().for_each(|option| {
+            ui.horizontal(|ui| match option {
+                TargeterOption::Radius => optional_number(
+                    ui,
+                    "Radius",
+                    "The radius to target entities in",
+                    &mut self.radius,
+                    0.1,
+                ),
+                TargeterOption::MinRadius => optional_number(
+                    ui,
+                    "Min Radius",
+                    "The inner radius of the ring",
+                    &mut self.min_radius,
+                    0.1,
+                ),
+                TargeterOption::MaxRadius => optional_number(
+                    ui,
+                    "Max Radius",
+                    "The outer radius of the ring",
+                    &mut self.max_radius,
+                    0.1,
+                ),
+                TargeterOption::Angle => {
+                    optional_number(ui, "Angle", "The angle of the cone", &mut self.angle, 0.1)
+                }
+                TargeterOption::Range => {
+                    optional_number(ui, "Range", "The length of the cone", &mut self.range, 0.1)
+                }
+                TargeterOption::Rotation => optional_number(
+                    ui,
+                    "Rotation",
+                    "The rotation of the cone relative to the facing direction",
+                    &mut self.rotation,
+                    0.1,
+                ),
+                TargeterOption::Types => optional_value(
+                    ui,
+                    "Types",
+                    "A list of mythic mobs or entity types, ex: SkeletalKnight,ZOMBIE",
+                    &mut self.types,
+                    String::new,
+                    |ui, value| {
+                        ui.text_edit_singleline(value);
+                    },
+                ),
+                TargeterOption::Limit => optional_value(
+                    ui,
+                    "Limit",
+                    "The maximum amount of targets",
+                    &mut self.limit,
+                    || 1,
+                    |ui, limit| {
+                        ui.add(egui::DragValue::new(limit));
+                    },
+                ),
+                TargeterOption::Sort => optional_value(
+                    ui,
+                    "Sort",
+                    "How the targets are ordered before the limit is applied",
+                    &mut self.sort,
+                    TargetSort::default,
+                    |ui, sort| {
+                        egui::ComboBox::new("Targeter_Sort", "")
+                            .selected_text(sort.to_string())
+                            .show_ui(ui, |ui| {
+                                TargetSort::iter().for_each(|v| {
+                                    let label = v.to_string();
+                                    ui.selectable_value(sort, v, label);
+                                });
+                            });
+                    },
+                ),
+                TargeterOption::Ignore => optional_value(
+                    ui,
+                    "Ignore",
+                    "A list of entity types to leave out",
+                    &mut self.ignore,
+                    String::new,
+                    |ui, value| {
+                        ui.text_edit_singleline(value);
+                    },
+                ),
+                TargeterOption::Conditions => {
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Conditions").on_hover_ui(|ui| {
+                                ui.label("Conditions every target has to meet");
+                            });
+                            if ui.button("Add Condition").clicked() {
+                                self.conditions.push(SkillCondition::default());
+                            }
+                        });
+                        let mut removed = None;
+                        self.conditions
+                            .iter_mut()
+                            .enumerate()
+                            .for_each(|(id, condition)| {
+                                ui.horizontal(|ui| {
+                                    condition.handle_ui(ui, ("Targeter_Condition", id));
+                                    if ui.button("Remove").clicked() {
+                                        removed = Some(id);
+                                    }
+                                });
+                            });
+                        if let Some(id) = removed {
+                            self.conditions.remove(id);
+                        }
+                    });
+                }
+            });
+        });
+    }
+}
+
+/// The order targets are picked in when a limit is set
+#[derive(
+    strum::Display, Clone, Debug, Default, PartialEq, Serialize, Deserialize, strum::EnumIter,
+)]
+pub enum TargetSort {
+    #[default]
+    #[strum(to_string = "NEAREST")]
+    Nearest,
+    #[strum(to_string = "FURTHEST")]
+    Furthest,
+    #[strum(to_string = "RANDOM")]
+    Random,
+    #[strum(to_string = "LOWEST_HEALTH")]
+    LowestHealth,
+    #[strum(to_string = "HIGHEST_HEALTH")]
+    HighestHealth,
+    #[strum(to_string = "HIGHEST_THREAT")]
+    HighestThreat,
+}
@@ -2,21 +2,23 @@ use std::default;
 
 use serde::{Deserialize, Serialize};
 
+use super::mechanics::MythicOption;
+
 #[derive(
-    Clone, Debug, PartialEq, Serialize, Deserialize, strum::VariantArray, strum::Display, Default,
+    Clone, Debug, PartialEq, Serialize, Deserialize, strum::EnumIter, strum::Display, Default,
 )]
 pub enum Trigger {
     #[strum(to_string = "~onCombat")]
-    ///Default
+    ///Default, used by mob skills written without a trigger
     Combat,
 
     #[strum(to_string = "~onAttack")]
     ///When the mob hits something
     Attack,
 
-    #[strum(to_string = "~onDamaged")]
-    ///When the mob is damaged
-    Damaged,
+    #[strum(to_string = "~onDamaged{0}")]
+    ///When the mob is damaged, optionally only by the given damage cause
+    Damaged(MythicOption<String>),
 
     #[strum(to_string = "~onSpawn")]
     ///When the mob spawns
@@ -42,13 +44,13 @@ pub enum Trigger {
     ///When the mob dies
     Death,
 
-    #[strum(to_string = "~onTimer")]
+    #[strum(to_string = "~onTimer:{0}")]
     ///Every # ticks (where # is the interval in ticks)
-    Timer,
+    Timer(u32),
 
-    #[strum(to_string = "~onInteract")]
-    /// When the mob is right-clicked
-    Interact,
+    #[strum(to_string = "~onInteract{0}")]
+    /// When the mob is right-clicked, optionally only with the given hand
+    Interact(MythicOption<String>),
 
     #[strum(to_string = "~onPlayerKill")]
     ///When the mob kills a player
@@ -82,9 +84,9 @@ pub enum Trigger {
     ///When the mob teleports (typically only used for endermen)
     Teleport,
 
-    #[strum(to_string = "~onSignal")]
-    ///When the mob receives a signal
-    Signal,
+    #[strum(to_string = "~onSignal:{0}")]
+    ///When the mob receives the given signal
+    Signal(String),
 
     #[strum(to_string = "~onShoot")]
     ///When the mob fires a projectile
@@ -118,9 +120,9 @@ pub enum Trigger {
     ///When the mob deals damage to other entities via a mechanic
     SkillDamage,
 
-    #[strum(to_string = "~onHear")]
-    ///When the mob hears a sound, [if enabled](https://git.mythiccraft.io/mythiccraft/MythicMobs/-/wikis/Mobs/Mobs#hearing)
-    Hear,
+    #[strum(to_string = "~onHear{0}")]
+    ///When the mob hears a sound, [if enabled](https://git.mythiccraft.io/mythiccraft/MythicMobs/-/wikis/Mobs/Mobs#hearing), optionally only the given sound type
+    Hear(MythicOption<String>),
 
     #[strum(to_string = "~onProjectileHit")]
     ///When a mob's special projectile hits an entity
@@ -140,15 +142,15 @@ impl Trigger {
         match self {
             Trigger::Combat => "Combat",
             Trigger::Attack => "Attack",
-            Trigger::Damaged => "Damaged",
+            Trigger::Damaged(_) => "Damaged",
             Trigger::Spawn => "Spawn",
             Trigger::Despawn => "Despawn",
             Trigger::Ready => "Ready",
             Trigger::Load => "Load",
             Trigger::SpawnOrLoad => "Spawn Or Load",
             Trigger::Death => "Death",
-            Trigger::Timer => "Timer",
-            Trigger::Interact => "Interact",
+            Trigger::Timer(_) => "Timer",
+            Trigger::Interact(_) => "Interact",
             Trigger::PlayerKill => "Player Kill",
             Trigger::EnterCombat => "Enter Combat",
             Trigger::DropCombat => "Drop Combat",
@@ -157,7 +159,7 @@ impl Trigger {
             Trigger::Prime => "Prime",
             Trigger::CreeperCharge => "Creeper Charge",
             Trigger::Teleport => "Teleport",
-            Trigger::Signal => "Signal",
+            Trigger::Signal(_) => "Signal",
             Trigger::Shoot => "Shoot",
             Trigger::BowHit => "Bow Hit",
             Trigger::Tame => "Tame",
@@ -166,10 +168,57 @@ impl Trigger {
             Trigger::ChangeWorld => "Change World",
             Trigger::Bucket => "Bucket",
             Trigger::SkillDamage => "Skill Damage",
-            Trigger::Hear => "Hear",
+            Trigger::Hear(_) => "Hear",
             Trigger::ProjectileHit => "Projectile Hit",
             Trigger::ProjectileLand => "Projectile Land",
             Trigger::None => "None",
         }
     }
+
+    /// The trigger as written in a skill line, the `:` is dropped when its argument is empty
+    pub fn config(&self) -> String {
+        let trigger = self.to_string();
+        let trigger = trigger.trim_end();
+        trigger.strip_suffix(':').unwrap_or(trigger).to_owned()
+    }
+
+    /// Set the argument written after `:`, false if the trigger takes none
+    pub fn set_arg(&mut self, arg: &str) -> bool {
+        match self {
+            Trigger::Timer(interval) => match arg.parse() {
+                Ok(ticks) => *interval = ticks,
+                Err(_) => return false,
+            },
+            Trigger::Signal(signal) => *signal = arg.to_owned(),
+            Trigger::Damaged(option) | Trigger::Interact(option) | Trigger::Hear(option) => {
+                *option = MythicOption::Some(":".to_owned(), arg.to_owned(), String::new())
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::parser::parse_skill_line;
+
+    #[test]
+    fn empty_arguments_are_dropped() {
+        assert_eq!(Trigger::Signal(String::new()).config(), "~onSignal");
+        assert_eq!(
+            Trigger::Damaged(MythicOption::Some(":".into(), String::new(), String::new())).config(),
+            "~onDamaged"
+        );
+        assert_eq!(Trigger::Signal("go".into()).config(), "~onSignal:go");
+        for trigger in [
+            Trigger::Signal(String::new()),
+            Trigger::Hear(MythicOption::None),
+        ] {
+            assert!(
+                parse_skill_line(&format!("- skill{{s=a}} @self {}", trigger.config())).is_ok()
+            );
+        }
+    }
 }
@@ -0,0 +1,70 @@
+//! Widgets shared by the skill editor and the project entry editors
+
+use super::mechanics::MythicOption;
+
+/// A checkbox that turns an optional value on and off, `edit` shows the value while it is on
+pub fn optional_value<T>(
+    ui: &mut egui::Ui,
+    label: &str,
+    hover: &str,
+    value: &mut Option<T>,
+    enable: impl FnOnce() -> T,
+    edit: impl FnOnce(&mut egui::Ui, &mut T),
+) {
+    let mut enabled = value.is_some();
+    ui.checkbox(&mut enabled, label).on_hover_ui(|ui| {
+        ui.label(hover);
+    });
+    match (enabled, value) {
+        (true, Some(value)) => edit(ui, value),
+        (true, value) => *value = Some(enable()),
+        (false, value) => *value = None,
+    }
+}
+
+/// An optional number edited with a drag value, 0 once it is turned on
+pub fn optional_number<N: egui::emath::Numeric + Default>(
+    ui: &mut egui::Ui,
+    label: &str,
+    hover: &str,
+    value: &mut Option<N>,
+    speed: f64,
+) {
+    optional_value(ui, label, hover, value, N::default, |ui, value| {
+        ui.add(egui::DragValue::new(value).speed(speed));
+    });
+}
+
+/// An optional chance edited with a slider, 1 once it is turned on
+pub fn optional_chance(ui: &mut egui::Ui, label: &str, hover: &str, value: &mut Option<f32>) {
+    optional_value(
+        ui,
+        label,
+        hover,
+        value,
+        || 1.0,
+        |ui, chance| {
+            ui.add(egui::Slider::new(chance, 0.0..=1.0));
+        },
+    );
+}
+
+/// `optional_value` for an optional argument, `prefix` is written before the value, ex: `;onTick=`
+pub fn optional_argument<T>(
+    ui: &mut egui::Ui,
+    label: &str,
+    hover: &str,
+    option: &mut MythicOption<T>,
+    prefix: &str,
+    enable: impl FnOnce() -> T,
+    edit: impl FnOnce(&mut egui::Ui, &mut T),
+) {
+    let (prefix, suffix, mut value) = match std::mem::replace(option, MythicOption::None) {
+        MythicOption::Some(prefix, value, suffix) => (prefix, suffix, Some(value)),
+        MythicOption::None => (prefix.to_owned(), String::new(), None),
+    };
+    optional_value(ui, label, hover, &mut value, enable, edit);
+    if let Some(value) = value {
+        *option = MythicOption::Some(prefix, value, suffix);
+    }
+}
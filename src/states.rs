@@ -1,163 +1,386 @@
-use crate::skill::{Skill, minecraft_lib::MinecraftMob, targeters::Targeter, triggers::Trigger};
+use std::path::{Path, PathBuf};
 
-#[derive(Default)]
+use droptable::DropTable;
+use item::Item;
+use metaskill::MetaSkill;
+use mob::Mob;
+use random_spawn::RandomSpawn;
+use serde::{Deserialize, Serialize};
+use skill_list::SkillList;
+use spawner::Spawner;
+
+use crate::{
+    export::{ExportSettings, write_section},
+    skill::{ProjectNames, mechanics::Mechanic},
+};
+
+pub mod ai_selectors;
+pub mod boss_bar;
+pub mod damage_modifiers;
+pub mod droptable;
+pub mod item;
+pub mod metaskill;
+pub mod mob;
+pub mod mob_equipment;
+pub mod mob_options;
+pub mod random_spawn;
+pub mod skill_list;
+pub mod spawner;
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppState {
-    pub mob_name: String,
-    pub mob_display_name: String,
-    pub mob_type: MinecraftMob,
-    pub mob_health: u32,
-    pub damage: u8,
-    pub armor: u8,
-    pub selected_skill: Option<usize>,
-    pub skills: Vec<Skill>,
+    pub mobs: Vec<Mob>,
+    pub metaskills: Vec<MetaSkill>,
+    pub droptables: Vec<DropTable>,
+    pub items: Vec<Item>,
+    pub random_spawns: Vec<RandomSpawn>,
+    pub spawners: Vec<Spawner>,
+    /// The entry being edited
+    pub selected: Option<Selection>,
+    pub export: ExportSettings,
+    #[serde(skip)]
     pub output: String,
+    /// Where the project was last opened from or saved to
+    #[serde(skip)]
+    pub project_path: Option<PathBuf>,
+}
+
+/// An entry of the project browser
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Selection {
+    Mob(usize),
+    MetaSkill(usize),
+    DropTable(usize),
+    Item(usize),
+    RandomSpawn(usize),
+    Spawner(usize),
 }
 
 impl AppState {
-    /// Replace the whitespaces to "_" and removes \n
-    pub fn sanitize_mob_name(&mut self) {
-        if self.mob_name.is_empty() {
-            if let MinecraftMob::MetaSkill = self.mob_type {
-                self.mob_name = "Default_Skill_Name".into();
-            } else {
-                self.mob_name = "Default_Mob_Name".into();
-            }
-        }
-        self.mob_name = self.mob_name.trim().replace(" ", "_");
+    /// Reset the state to an empty project
+    pub fn new_project(&mut self) {
+        *self = Self::default();
     }
-    /// Append a default skill to self.skills and select it
-    pub fn add_skill(&mut self) {
-        self.skills
-            .push(Skill::default().set_name(format!("Skill_{}", self.skills.len())));
-        self.select_last_skill();
+    /// Read a project file and replace the current state with it
+    pub fn open_project(&mut self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        let mut state: AppState = ron::from_str(&content)
+            .map_err(|e| format!("Invalid project file {}: {e}", path.display()))?;
+        state.project_path = Some(path.to_path_buf());
+        *self = state;
+        Ok(())
     }
-    pub fn get_selected_skill(&mut self) -> Option<&mut Skill> {
-        if let Some(id) = self.selected_skill {
-            return self.skills.get_mut(id);
+    /// The selected mob
+    pub fn selected_mob_mut(&mut self) -> Option<&mut Mob> {
+        match self.selected? {
+            Selection::Mob(id) => self.mobs.get_mut(id),
+            _ => None,
         }
-        None
     }
-    /// Rename Selected Skill(self.selected_skill) name
-    pub fn rename_selected_skill(&mut self, name: impl Into<String>) {
-        if let Some(id) = self.selected_skill {
-            if let Some(sk) = self.skills.get_mut(id) {
-                sk.rename(name);
-            }
+    /// The selected metaskill
+    pub fn selected_metaskill_mut(&mut self) -> Option<&mut MetaSkill> {
+        match self.selected? {
+            Selection::MetaSkill(id) => self.metaskills.get_mut(id),
+            _ => None,
         }
     }
-    /// Remove the Selected Skill(self.selected_skill) from self.skills
-    pub fn remove_selected_skill(&mut self) {
-        if let Some(id) = self.selected_skill {
-            if self.skills.get(id).is_some() {
-                self.skills.remove(id);
-            }
+    /// The selected droptable
+    pub fn selected_droptable_mut(&mut self) -> Option<&mut DropTable> {
+        match self.selected? {
+            Selection::DropTable(id) => self.droptables.get_mut(id),
+            _ => None,
         }
     }
-    /// set the selected skill to the first one in self.skills
-    /// if empty, set to None
-    pub fn select_first_skill(&mut self) {
-        if self.skills.is_empty() {
-            self.selected_skill = None;
-            return;
+    /// The selected item
+    pub fn selected_item_mut(&mut self) -> Option<&mut Item> {
+        match self.selected? {
+            Selection::Item(id) => self.items.get_mut(id),
+            _ => None,
         }
-        self.select_skill(0);
-    }
-    /// set the selected skill to the last one in self.skills
-    /// if empty, set to None
-    pub fn select_last_skill(&mut self) {
-        if self.skills.is_empty() {
-            self.selected_skill = None;
-            return;
+    }
+    /// The selected random spawn
+    pub fn selected_random_spawn_mut(&mut self) -> Option<&mut RandomSpawn> {
+        match self.selected? {
+            Selection::RandomSpawn(id) => self.random_spawns.get_mut(id),
+            _ => None,
         }
-        self.select_skill(self.skills.len() - 1);
     }
-    /// remove skill from self.skills at index
-    pub fn remove_skill(&mut self, id: usize) {
-        if (0..=self.skills.len() - 1).contains(&id) && self.skills.get(id).is_some() {
-            self.skills.remove(id);
+    /// The selected spawner
+    pub fn selected_spawner_mut(&mut self) -> Option<&mut Spawner> {
+        match self.selected? {
+            Selection::Spawner(id) => self.spawners.get_mut(id),
+            _ => None,
         }
     }
-    pub fn select_skill(&mut self, id: usize) {
-        if self.skills.is_empty() {
-            self.selected_skill = None;
-            return;
+    /// Names of the project entries that skills can reference
+    pub fn project_names(&self) -> ProjectNames {
+        let mut spawner_groups: Vec<String> = self
+            .spawners
+            .iter()
+            .map(|spawner| spawner.group.trim().to_owned())
+            .filter(|group| !group.is_empty())
+            .collect();
+        spawner_groups.sort();
+        spawner_groups.dedup();
+        ProjectNames {
+            metaskills: self.metaskills.iter().map(|m| m.name.clone()).collect(),
+            spawners: self.spawners.iter().map(|s| s.name.clone()).collect(),
+            spawner_groups,
         }
-        if (0..=self.skills.len() - 1).contains(&id) {
-            self.selected_skill = Some(id);
+    }
+    /// Internal names of the mobs of the project
+    pub fn mob_names(&self) -> Vec<String> {
+        self.mobs.iter().map(|mob| mob.mob_name.clone()).collect()
+    }
+    /// Custom damage elements used by the damage mechanics of the project
+    pub fn damage_elements(&self) -> Vec<String> {
+        let mob_skills = self.mobs.iter().flat_map(|mob| mob.skills.iter());
+        let metaskill_skills = self.metaskills.iter().flat_map(|m| m.skills.iter());
+        let mut elements: Vec<String> = mob_skills
+            .chain(metaskill_skills)
+            .filter_map(|sk| match &sk.mechanic {
+                Some(Mechanic::Damage { element, .. }) => Some(element.clone()),
+                Some(Mechanic::ModifyDamage { damage_type, .. }) => Some(damage_type.clone()),
+                _ => None,
+            })
+            .filter(|element| !element.trim().is_empty())
+            .collect();
+        elements.sort();
+        elements.dedup();
+        elements
+    }
+    /// Names of the droptables of the project
+    pub fn droptable_names(&self) -> Vec<String> {
+        self.droptables.iter().map(|t| t.name.clone()).collect()
+    }
+    /// The skill list of the selected mob, metaskill or item
+    pub fn selected_skill_list(&mut self) -> Option<&mut dyn SkillList> {
+        match self.selected? {
+            Selection::Mob(id) => Some(self.mobs.get_mut(id)? as &mut dyn SkillList),
+            Selection::MetaSkill(id) => Some(self.metaskills.get_mut(id)? as &mut dyn SkillList),
+            Selection::Item(id) => Some(self.items.get_mut(id)? as &mut dyn SkillList),
+            Selection::DropTable(_) | Selection::RandomSpawn(_) | Selection::Spawner(_) => None,
         }
     }
-    pub fn create_config(&mut self) {
-        self.sanitize_mob_name();
-        self.output = format!(
-            "{}:{}{}{}{}{}{}{}{}{}{}{}{}",
-            self.mob_name,
-            if let MinecraftMob::MetaSkill = self.mob_type {
-                ""
-            } else {
-                "\n  Type: "
-            },
-            if let MinecraftMob::MetaSkill = self.mob_type {
-                "".to_string()
-            } else {
-                self.mob_type.to_string()
-            },
-            if self.mob_display_name.is_empty() {
-                ""
-            } else {
-                "\n  Display: "
-            },
-            self.mob_display_name,
-            if self.mob_health == 0 {
-                ""
-            } else {
-                "\n  Health: "
-            },
-            if self.mob_health == 0 {
-                "".to_owned()
-            } else {
-                self.mob_health.to_string()
-            },
-            if self.damage == 0 { "" } else { "\n  Damage: " },
-            if self.damage == 0 {
-                "".to_owned()
-            } else {
-                self.damage.to_string()
-            },
-            if self.armor == 0 { "" } else { "\n  Armor: " },
-            if self.armor == 0 {
-                "".to_owned()
-            } else {
-                self.armor.to_string()
-            },
-            if self.skills.is_empty() {
-                ""
-            } else {
-                "\n  Skills:"
-            },
-            // SKILLS
-            self.handle_skill_formating(),
-        );
-    }
-    pub fn handle_skill_formating(&self) -> String {
-        let mut skills_fmt = String::new();
-        self.skills.iter().for_each(|sk| {
-            if let Some(mech) = &sk.mechanic {
-                skills_fmt.push_str(&format!(
-                    "\n  {}{}{}",
-                    mech,
-                    if let Targeter::None = sk.targeter {
-                        "".to_owned()
-                    } else {
-                        format!(" {}", sk.targeter)
-                    },
-                    if let Trigger::None = sk.trigger {
-                        "".to_owned()
-                    } else {
-                        format!(" {}", sk.trigger)
-                    },
-                ));
+    /// Append a default mob and select it
+    pub fn add_mob(&mut self) {
+        self.mobs.push(Mob {
+            mob_name: format!("Mob_{}", self.mobs.len()),
+            ..Default::default()
+        });
+        self.selected = Some(Selection::Mob(self.mobs.len() - 1));
+    }
+    /// Append a default metaskill and select it
+    pub fn add_metaskill(&mut self) {
+        self.metaskills.push(MetaSkill {
+            name: format!("MetaSkill_{}", self.metaskills.len()),
+            ..Default::default()
+        });
+        self.selected = Some(Selection::MetaSkill(self.metaskills.len() - 1));
+    }
+    /// Append a default droptable and select it
+    pub fn add_droptable(&mut self) {
+        self.droptables.push(DropTable {
+            name: format!("DropTable_{}", self.droptables.len()),
+            ..Default::default()
+        });
+        self.selected = Some(Selection::DropTable(self.droptables.len() - 1));
+    }
+    /// Append a default item and select it
+    pub fn add_item(&mut self) {
+        self.items.push(Item {
+            name: format!("Item_{}", self.items.len()),
+            ..Default::default()
+        });
+        self.selected = Some(Selection::Item(self.items.len() - 1));
+    }
+    /// Append a default random spawn and select it
+    pub fn add_random_spawn(&mut self) {
+        self.random_spawns.push(RandomSpawn {
+            name: format!("RandomSpawn_{}", self.random_spawns.len()),
+            mob: self
+                .mobs
+                .first()
+                .map(|mob| mob.mob_name.clone())
+                .unwrap_or_default(),
+            ..Default::default()
+        });
+        self.selected = Some(Selection::RandomSpawn(self.random_spawns.len() - 1));
+    }
+    /// Append a default spawner and select it
+    pub fn add_spawner(&mut self) {
+        self.spawners.push(Spawner {
+            name: format!("Spawner_{}", self.spawners.len()),
+            mob_name: self
+                .mobs
+                .first()
+                .map(|mob| mob.mob_name.clone())
+                .unwrap_or_default(),
+            ..Default::default()
+        });
+        self.selected = Some(Selection::Spawner(self.spawners.len() - 1));
+    }
+    /// Remove the selected entry and select its neighbour
+    pub fn remove_selected(&mut self) {
+        self.selected = match self.selected {
+            Some(Selection::Mob(id)) if id < self.mobs.len() => {
+                self.mobs.remove(id);
+                (!self.mobs.is_empty()).then(|| Selection::Mob(id.min(self.mobs.len() - 1)))
+            }
+            Some(Selection::MetaSkill(id)) if id < self.metaskills.len() => {
+                self.metaskills.remove(id);
+                (!self.metaskills.is_empty())
+                    .then(|| Selection::MetaSkill(id.min(self.metaskills.len() - 1)))
+            }
+            Some(Selection::DropTable(id)) if id < self.droptables.len() => {
+                self.droptables.remove(id);
+                (!self.droptables.is_empty())
+                    .then(|| Selection::DropTable(id.min(self.droptables.len() - 1)))
+            }
+            Some(Selection::Item(id)) if id < self.items.len() => {
+                self.items.remove(id);
+                (!self.items.is_empty()).then(|| Selection::Item(id.min(self.items.len() - 1)))
             }
+            Some(Selection::RandomSpawn(id)) if id < self.random_spawns.len() => {
+                self.random_spawns.remove(id);
+                (!self.random_spawns.is_empty())
+                    .then(|| Selection::RandomSpawn(id.min(self.random_spawns.len() - 1)))
+            }
+            Some(Selection::Spawner(id)) if id < self.spawners.len() => {
+                self.spawners.remove(id);
+                (!self.spawners.is_empty())
+                    .then(|| Selection::Spawner(id.min(self.spawners.len() - 1)))
+            }
+            selected => selected,
+        };
+    }
+    /// Show the config of the selected entry in self.output
+    pub fn create_config(&mut self) {
+        self.output = match self.selected {
+            Some(Selection::Mob(_)) => self.selected_mob_mut().map(|mob| mob.create_config()),
+            Some(Selection::MetaSkill(_)) => self
+                .selected_metaskill_mut()
+                .map(|metaskill| metaskill.create_config()),
+            Some(Selection::DropTable(_)) => self
+                .selected_droptable_mut()
+                .map(|droptable| droptable.create_config()),
+            Some(Selection::Item(_)) => self.selected_item_mut().map(|item| item.create_config()),
+            Some(Selection::RandomSpawn(_)) => self
+                .selected_random_spawn_mut()
+                .and_then(|random_spawn| random_spawn.create_config()),
+            Some(Selection::Spawner(_)) => self
+                .selected_spawner_mut()
+                .and_then(|spawner| spawner.create_config()),
+            None => None,
+        }
+        .unwrap_or_default();
+    }
+    /// Add every mob of a MythicMobs mob file to the project, replacing the mobs
+    /// with the same internal name, and return a summary of what was imported
+    pub fn import_mob_file(&mut self, path: impl AsRef<Path>) -> Result<String, String> {
+        let (mobs, ignored) = crate::import::read_mob_file(path)?;
+        let count = mobs.len();
+        let mut first = None;
+        for mob in mobs {
+            let id = match self.mobs.iter().position(|m| m.mob_name == mob.mob_name) {
+                Some(id) => {
+                    self.mobs[id] = mob;
+                    id
+                }
+                None => {
+                    self.mobs.push(mob);
+                    self.mobs.len() - 1
+                }
+            };
+            first.get_or_insert(id);
+        }
+        let Some(first) = first else {
+            return Err("No mob found in the file".to_owned());
+        };
+        self.selected = Some(Selection::Mob(first));
+        if ignored.is_empty() {
+            return Ok(format!("Imported {count} mob(s)"));
+        }
+        Ok(format!(
+            "Imported {count} mob(s), not imported: {}",
+            ignored.join(", ")
+        ))
+    }
+    /// Write every mob in the `Mobs/` folder of the pack, every metaskill in
+    /// its `Skills/` folder, every droptable in its `DropTables/` folder, every
+    /// item in its `Items/` folder, every random spawn in its `RandomSpawns/`
+    /// folder and every spawner in its `Spawners/` folder, and return the
+    /// written files
+    pub fn export_to_pack(&mut self) -> Result<Vec<PathBuf>, String> {
+        if self.export.pack_dir.trim().is_empty() {
+            return Err("Choose the pack folder to export to".to_owned());
+        }
+        let mut sections: Vec<(&str, String, String)> = Vec::new();
+        let mobs = self.mobs.iter_mut().map(|mob| {
+            let config = mob.create_config();
+            ("Mobs", mob.mob_name.clone(), config)
+        });
+        let metaskills = self.metaskills.iter_mut().map(|metaskill| {
+            let config = metaskill.create_config();
+            ("Skills", metaskill.name.clone(), config)
+        });
+        let droptables = self.droptables.iter_mut().map(|droptable| {
+            let config = droptable.create_config();
+            ("DropTables", droptable.name.clone(), config)
+        });
+        let items = self.items.iter_mut().map(|item| {
+            let config = item.create_config();
+            ("Items", item.name.clone(), config)
         });
-        skills_fmt
+        // Random spawns and spawners without a mob are not written
+        let random_spawns = self.random_spawns.iter_mut().filter_map(|random_spawn| {
+            let config = random_spawn.create_config()?;
+            Some(("RandomSpawns", random_spawn.name.clone(), config))
+        });
+        let spawners = self.spawners.iter_mut().filter_map(|spawner| {
+            let config = spawner.create_config()?;
+            Some(("Spawners", spawner.name.clone(), config))
+        });
+        let entries = mobs
+            .chain(metaskills)
+            .chain(droptables)
+            .chain(items)
+            .chain(random_spawns)
+            .chain(spawners);
+        for (folder, name, config) in entries {
+            if sections.iter().any(|(f, n, _)| *f == folder && *n == name) {
+                return Err(format!("{name} is used twice in {folder}"));
+            }
+            sections.push((folder, name, config));
+        }
+        let mut written: Vec<PathBuf> = Vec::new();
+        for (folder, name, config) in sections {
+            let path = self.export.file_path(folder, &name);
+            write_section(&path, &name, &config)?;
+            if !written.contains(&path) {
+                written.push(path);
+            }
+        }
+        Ok(written)
+    }
+    /// Write the project to self.project_path
+    pub fn save_project(&mut self) -> Result<(), String> {
+        match self.project_path.clone() {
+            Some(path) => self.save_project_as(path),
+            None => Err("The project has never been saved, use Save As".to_owned()),
+        }
+    }
+    /// Write the project to path and remember it for the next save
+    pub fn save_project_as(&mut self, path: impl Into<PathBuf>) -> Result<(), String> {
+        let path = path.into();
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Could not serialize the project: {e}"))?;
+        std::fs::write(&path, content)
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+        self.project_path = Some(path);
+        Ok(())
     }
 }
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// A line of the `AIGoalSelectors:` section, the first goals have the highest priority
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum AIGoalSelector {
+    /// Remove the default goals of the entity type
+    #[default]
+    #[strum(to_string = "clear")]
+    Clear,
+    #[strum(to_string = "float")]
+    Float,
+    #[strum(to_string = "meleeattack")]
+    MeleeAttack,
+    #[strum(to_string = "leapattarget")]
+    LeapAtTarget,
+    #[strum(to_string = "movetowardstarget")]
+    MoveTowardsTarget,
+    #[strum(to_string = "randomstroll")]
+    RandomStroll,
+    #[strum(to_string = "gotospawnlocation")]
+    GoToSpawnLocation,
+    #[strum(to_string = "lookatplayers")]
+    LookAtPlayers,
+    #[strum(to_string = "randomlookaround")]
+    RandomLookAround,
+    #[strum(to_string = "opendoors")]
+    OpenDoors,
+    #[strum(to_string = "breakdoors")]
+    BreakDoors,
+    #[strum(to_string = "restrictsun")]
+    RestrictSun,
+    #[strum(to_string = "fleesun")]
+    FleeSun,
+    /// A goal written as is, ex: `gotolocation{l=0,64,0}`
+    #[strum(to_string = "{0}")]
+    Custom(String),
+}
+
+impl AIGoalSelector {
+    pub fn get_fields(&self) -> String {
+        match self {
+            AIGoalSelector::Custom(_) => "Custom".to_owned(),
+            goal => goal.to_string(),
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash + Copy) {
+        egui::ComboBox::new(("ai_goal_selector", id_salt), "")
+            .selected_text(self.get_fields())
+            .show_ui(ui, |ui| {
+                AIGoalSelector::iter().for_each(|v| {
+                    let selected = std::mem::discriminant(self) == std::mem::discriminant(&v);
+                    if ui.selectable_label(selected, v.get_fields()).clicked() && !selected {
+                        *self = v;
+                    }
+                });
+            });
+        if let AIGoalSelector::Custom(goal) = self {
+            ui.text_edit_singleline(goal);
+        }
+    }
+}
+
+/// A line of the `AITargetSelectors:` section, the first selectors have the highest priority
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum AITargetSelector {
+    /// Remove the default target selectors of the entity type
+    #[default]
+    #[strum(to_string = "clear")]
+    Clear,
+    /// Target the entities that damaged the mob
+    #[strum(to_string = "attacker")]
+    Attacker,
+    #[strum(to_string = "players")]
+    Players,
+    #[strum(to_string = "villagers")]
+    Villagers,
+    #[strum(to_string = "monsters")]
+    Monsters,
+    #[strum(to_string = "otherfactions")]
+    OtherFactions,
+    #[strum(to_string = "otherfactionmonsters")]
+    OtherFactionMonsters,
+    #[strum(to_string = "otherfactionvillagers")]
+    OtherFactionVillagers,
+    #[strum(to_string = "specificfaction {0}")]
+    SpecificFaction(String),
+    /// A selector written as is
+    #[strum(to_string = "{0}")]
+    Custom(String),
+}
+
+impl AITargetSelector {
+    pub fn get_fields(&self) -> String {
+        match self {
+            AITargetSelector::SpecificFaction(_) => "specificfaction".to_owned(),
+            AITargetSelector::Custom(_) => "Custom".to_owned(),
+            selector => selector.to_string(),
+        }
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, id_salt: impl std::hash::Hash + Copy) {
+        egui::ComboBox::new(("ai_target_selector", id_salt), "")
+            .selected_text(self.get_fields())
+            .show_ui(ui, |ui| {
+                AITargetSelector::iter().for_each(|v| {
+                    let selected = std::mem::discriminant(self) == std::mem::discriminant(&v);
+                    if ui.selectable_label(selected, v.get_fields()).clicked() && !selected {
+                        *self = v;
+                    }
+                });
+            });
+        match self {
+            AITargetSelector::SpecificFaction(faction) => {
+                ui.add(egui::TextEdit::singleline(faction).hint_text("Faction"));
+            }
+            AITargetSelector::Custom(selector) => {
+                ui.text_edit_singleline(selector);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An AI selector section in priority order, empty when there is no selector
+pub fn selectors_config(section: &str, selectors: &[impl std::fmt::Display]) -> String {
+    if selectors.is_empty() {
+        return String::new();
+    }
+    let mut config = format!("\n  {section}:");
+    selectors.iter().for_each(|selector| {
+        config.push_str(&format!("\n  - {selector}"));
+    });
+    config
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::export::yaml_quote;
+
+/// The `BossBar:` section of a mob
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BossBar {
+    pub enabled: bool,
+    /// Text above the bar, the display name of the mob when empty
+    pub title: String,
+    /// Distance in blocks at which players see the bar
+    pub range: u32,
+    pub color: BarColor,
+    pub style: BarStyle,
+    /// Add fog around the players who see the bar
+    pub create_fog: bool,
+    /// Darken the sky like during the wither fight
+    pub darken_sky: bool,
+    /// Play the ender dragon music
+    pub play_music: bool,
+}
+
+impl Default for BossBar {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            title: String::new(),
+            range: 50,
+            color: BarColor::default(),
+            style: BarStyle::default(),
+            create_fog: false,
+            darken_sky: false,
+            play_music: false,
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
+pub enum BarColor {
+    #[strum(to_string = "PINK")]
+    Pink,
+    #[strum(to_string = "BLUE")]
+    Blue,
+    #[default]
+    #[strum(to_string = "RED")]
+    Red,
+    #[strum(to_string = "GREEN")]
+    Green,
+    #[strum(to_string = "YELLOW")]
+    Yellow,
+    #[strum(to_string = "PURPLE")]
+    Purple,
+    #[strum(to_string = "WHITE")]
+    White,
+}
+
+#[derive(
+    Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
+pub enum BarStyle {
+    #[default]
+    #[strum(to_string = "SOLID")]
+    Solid,
+    #[strum(to_string = "SEGMENTED_6")]
+    Segmented6,
+    #[strum(to_string = "SEGMENTED_10")]
+    Segmented10,
+    #[strum(to_string = "SEGMENTED_12")]
+    Segmented12,
+    #[strum(to_string = "SEGMENTED_20")]
+    Segmented20,
+}
+
+impl BossBar {
+    /// The `BossBar:` section, empty when the bar is disabled
+    pub fn create_config(&self) -> String {
+        if !self.enabled {
+            return String::new();
+        }
+        let mut config = String::from("\n  BossBar:\n    Enabled: true");
+        if !self.title.is_empty() {
+            config.push_str(&format!("\n    Title: {}", yaml_quote(&self.title)));
+        }
+        config.push_str(&format!(
+            "\n    Range: {}\n    Color: {}\n    Style: {}",
+            self.range, self.color, self.style
+        ));
+        [
+            ("CreateFog", self.create_fog),
+            ("DarkenSky", self.darken_sky),
+            ("PlayMusic", self.play_music),
+        ]
+        .iter()
+        .filter(|(_, value)| *value)
+        .for_each(|(key, _)| {
+            config.push_str(&format!("\n    {key}: true"));
+        });
+        config
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.enabled, "Enabled").on_hover_ui(|ui| {
+            ui.label("Show a boss bar with the health of the mob");
+        });
+        if !self.enabled {
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label("Title").on_hover_ui(|ui| {
+                ui.label("Text above the bar, the display name of the mob when empty");
+            });
+            ui.text_edit_singleline(&mut self.title);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Range").on_hover_ui(|ui| {
+                ui.label("Distance in blocks at which players see the bar");
+            });
+            ui.add(egui::DragValue::new(&mut self.range));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Color");
+            egui::ComboBox::new("Boss_Bar_Color", "")
+                .selected_text(self.color.to_string())
+                .show_ui(ui, |ui| {
+                    BarColor::iter().for_each(|v| {
+                        ui.selectable_value(&mut self.color, v, v.to_string());
+                    });
+                });
+            ui.label("Style");
+            egui::ComboBox::new("Boss_Bar_Style", "")
+                .selected_text(self.style.to_string())
+                .show_ui(ui, |ui| {
+                    BarStyle::iter().for_each(|v| {
+                        ui.selectable_value(&mut self.style, v, v.to_string());
+                    });
+                });
+        });
+        ui.checkbox(&mut self.create_fog, "Create Fog")
+            .on_hover_ui(|ui| {
+                ui.label("Add fog around the players who see the bar");
+            });
+        ui.checkbox(&mut self.darken_sky, "Darken Sky")
+            .on_hover_ui(|ui| {
+                ui.label("Darken the sky like during the wither fight");
+            });
+        ui.checkbox(&mut self.play_music, "Play Music")
+            .on_hover_ui(|ui| {
+                ui.label("Play the ender dragon music");
+            });
+    }
+}
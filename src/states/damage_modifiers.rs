@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+/// A line of the `DamageModifiers:` section, ex: `- FIRE 0.5`
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DamageModifier {
+    pub cause: DamageCause,
+    /// Multiplier of the damage taken, 0 for immunity and below 0 to heal
+    pub multiplier: f32,
+}
+
+impl Default for DamageModifier {
+    fn default() -> Self {
+        Self {
+            cause: DamageCause::default(),
+            multiplier: 1.0,
+        }
+    }
+}
+
+impl std::fmt::Display for DamageModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "- {} {}", self.cause, self.multiplier)
+    }
+}
+
+/// The `DamageModifiers:` section, empty when the mob has no modifier
+pub fn damage_modifiers_config(modifiers: &[DamageModifier]) -> String {
+    if modifiers.is_empty() {
+        return String::new();
+    }
+    let mut config = String::from("\n  DamageModifiers:");
+    modifiers.iter().for_each(|modifier| {
+        config.push_str(&format!("\n  {modifier}"));
+    });
+    config
+}
+
+impl DamageModifier {
+    /// Whether this modifier uses a custom element that no damage mechanic of the
+    /// project uses, the element may still come from another pack
+    pub fn unknown_element(&self, elements: &[String]) -> bool {
+        matches!(&self.cause, DamageCause::Element(element) if !elements.contains(element))
+    }
+
+    /// `elements` are the custom damage elements used by the mechanics of the project
+    pub fn handle_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: impl std::hash::Hash + Copy,
+        elements: &[String],
+    ) {
+        egui::ComboBox::new(("damage_cause", id_salt), "")
+            .selected_text(self.cause.get_fields().into())
+            .show_ui(ui, |ui| {
+                DamageCause::iter().for_each(|v| {
+                    let label = v.get_fields().into();
+                    let selected =
+                        std::mem::discriminant(&self.cause) == std::mem::discriminant(&v);
+                    if ui.selectable_label(selected, label).clicked() && !selected {
+                        self.cause = match v {
+                            DamageCause::Element(_) => {
+                                DamageCause::Element(elements.first().cloned().unwrap_or_default())
+                            }
+                            v => v,
+                        };
+                    }
+                });
+            });
+        let unknown = self.unknown_element(elements);
+        if let DamageCause::Element(element) = &mut self.cause {
+            egui::ComboBox::new(("damage_element", id_salt), "")
+                .selected_text(element.clone())
+                .show_ui(ui, |ui| {
+                    elements.iter().for_each(|e| {
+                        ui.selectable_value(element, e.clone(), e);
+                    });
+                });
+            ui.add(egui::TextEdit::singleline(element).desired_width(80.0));
+            if unknown {
+                ui.colored_label(egui::Color32::YELLOW, "⚠")
+                    .on_hover_text(
+                        "No damage mechanic of the project uses this element, check that another pack defines it",
+                    );
+            }
+        }
+        ui.add(egui::DragValue::new(&mut self.multiplier).speed(0.05))
+            .on_hover_ui(|ui| {
+                ui.label("Multiplier of the damage taken, 0 for immunity and below 0 to heal");
+            });
+    }
+}
+
+/// The damage causes of Minecraft, or a custom element of the damage mechanic
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum DamageCause {
+    #[default]
+    #[strum(to_string = "ENTITY_ATTACK")]
+    EntityAttack,
+    #[strum(to_string = "ENTITY_SWEEP_ATTACK")]
+    EntitySweepAttack,
+    #[strum(to_string = "PROJECTILE")]
+    Projectile,
+    #[strum(to_string = "FIRE")]
+    Fire,
+    #[strum(to_string = "FIRE_TICK")]
+    FireTick,
+    #[strum(to_string = "LAVA")]
+    Lava,
+    #[strum(to_string = "FALL")]
+    Fall,
+    #[strum(to_string = "DROWNING")]
+    Drowning,
+    #[strum(to_string = "SUFFOCATION")]
+    Suffocation,
+    #[strum(to_string = "BLOCK_EXPLOSION")]
+    BlockExplosion,
+    #[strum(to_string = "ENTITY_EXPLOSION")]
+    EntityExplosion,
+    #[strum(to_string = "LIGHTNING")]
+    Lightning,
+    #[strum(to_string = "MAGIC")]
+    Magic,
+    #[strum(to_string = "POISON")]
+    Poison,
+    #[strum(to_string = "WITHER")]
+    Wither,
+    #[strum(to_string = "THORNS")]
+    Thorns,
+    #[strum(to_string = "FREEZE")]
+    Freeze,
+    #[strum(to_string = "VOID")]
+    Void,
+    /// The `element` of a damage mechanic
+    #[strum(to_string = "{0}")]
+    Element(String),
+}
+
+impl DamageCause {
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            DamageCause::Element(_) => "Custom Element".to_owned(),
+            cause => cause.to_string(),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+
+use crate::skill::{conditions::SkillCondition, ui::optional_chance};
+
+/// A droptable of the project, written in the `DropTables/` folder of the pack
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DropTable {
+    pub name: String,
+    /// Exact number of drops picked from the table
+    pub total_items: Option<u32>,
+    /// Minimum number of drops picked from the table
+    pub min_items: Option<u32>,
+    /// Maximum number of drops picked from the table
+    pub max_items: Option<u32>,
+    /// Conditions checked before the table drops anything
+    pub conditions: Vec<SkillCondition>,
+    pub drops: Vec<DropLine>,
+}
+
+impl DropTable {
+    /// Replace the whitespaces to "_" and removes \n
+    pub fn sanitize_name(&mut self) {
+        if self.name.is_empty() {
+            self.name = "Default_DropTable_Name".into();
+        }
+        self.name = self.name.trim().replace(" ", "_");
+    }
+    /// Generate the yaml section of this droptable
+    pub fn create_config(&mut self) -> String {
+        self.sanitize_name();
+        let mut config = format!("{}:", self.name);
+        [
+            ("TotalItems", self.total_items),
+            ("MinItems", self.min_items),
+            ("MaxItems", self.max_items),
+        ]
+        .iter()
+        .for_each(|(key, value)| {
+            if let Some(value) = value {
+                config.push_str(&format!("\n  {key}: {value}"));
+            }
+        });
+        if !self.conditions.is_empty() {
+            config.push_str("\n  Conditions:");
+            self.conditions.iter().for_each(|c| {
+                config.push_str(&format!("\n  {c}"));
+            });
+        }
+        config.push_str(&drops_config(&self.drops));
+        config
+    }
+}
+
+/// The `Drops:` section of a mob or droptable, empty when there is no drop.
+///
+/// Drops of an item or droptable without a name are skipped
+pub fn drops_config(drops: &[DropLine]) -> String {
+    let drops: Vec<&DropLine> = drops.iter().filter(|drop| !drop.kind.is_empty()).collect();
+    if drops.is_empty() {
+        return String::new();
+    }
+    let mut config = String::from("\n  Drops:");
+    drops.iter().for_each(|drop| {
+        config.push_str(&format!("\n  {drop}"));
+    });
+    config
+}
+
+/// A line of a drop list, ex: `- DIAMOND 1-3 0.5`
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DropLine {
+    pub kind: DropKind,
+    pub amount: DropAmount,
+    /// Chance for the drop to happen, between 0 and 1
+    pub chance: Option<f32>,
+}
+
+impl std::fmt::Display for DropLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "- {} {}", self.kind, self.amount)?;
+        if let Some(chance) = self.chance {
+            write!(f, " {chance}")?;
+        }
+        Ok(())
+    }
+}
+
+impl DropLine {
+    /// `droptables` are the names offered for droptable references
+    pub fn handle_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: impl std::hash::Hash + Copy,
+        droptables: &[String],
+    ) {
+        self.kind.handle_ui(ui, id_salt, droptables);
+        self.amount.handle_ui(ui);
+        optional_chance(
+            ui,
+            "Chance",
+            "Chance for the drop to happen",
+            &mut self.chance,
+        );
+    }
+}
+
+/// What a drop line gives
+#[derive(Clone, PartialEq, Serialize, Deserialize, strum::Display)]
+pub enum DropKind {
+    /// A vanilla or Mythic item
+    #[strum(to_string = "{0}")]
+    Item(String),
+    /// Experience orbs
+    #[strum(to_string = "exp")]
+    Exp,
+    /// Money given through Vault
+    #[strum(to_string = "money")]
+    Money,
+    /// Every drop of another droptable
+    #[strum(to_string = "{0}")]
+    DropTable(String),
+}
+
+impl Default for DropKind {
+    fn default() -> Self {
+        Self::Item("DIAMOND".to_owned())
+    }
+}
+
+impl DropKind {
+    /// An item or droptable reference without a name
+    pub fn is_empty(&self) -> bool {
+        match self {
+            DropKind::Item(name) | DropKind::DropTable(name) => name.trim().is_empty(),
+            DropKind::Exp | DropKind::Money => false,
+        }
+    }
+
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            DropKind::Item(_) => "Item",
+            DropKind::Exp => "Exp",
+            DropKind::Money => "Money",
+            DropKind::DropTable(_) => "DropTable",
+        }
+    }
+
+    fn handle_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: impl std::hash::Hash + Copy,
+        droptables: &[String],
+    ) {
+        egui::ComboBox::new(("drop_kind", id_salt), "")
+            .selected_text(self.get_fields().into())
+            .show_ui(ui, |ui| {
+                [
+                    DropKind::default(),
+                    DropKind::Exp,
+                    DropKind::Money,
+                    DropKind::DropTable(droptables.first().cloned().unwrap_or_default()),
+                ]
+                .into_iter()
+                .for_each(|v| {
+                    let label = v.get_fields().into();
+                    let selected = std::mem::discriminant(self) == std::mem::discriminant(&v);
+                    let enabled = !matches!(v, DropKind::DropTable(_)) || !droptables.is_empty();
+                    let clicked = ui
+                        .add_enabled_ui(enabled, |ui| ui.selectable_label(selected, label))
+                        .inner
+                        .on_disabled_hover_text("The project has no droptable")
+                        .clicked();
+                    if clicked && !selected {
+                        *self = v;
+                    }
+                });
+            });
+        match self {
+            DropKind::Item(item) => {
+                ui.add(egui::TextEdit::singleline(item).hint_text("DIAMOND"));
+            }
+            DropKind::DropTable(name) => {
+                egui::ComboBox::new(("drop_table", id_salt), "")
+                    .selected_text(name.clone())
+                    .show_ui(ui, |ui| {
+                        droptables.iter().for_each(|droptable| {
+                            ui.selectable_value(name, droptable.clone(), droptable);
+                        });
+                    });
+            }
+            DropKind::Exp | DropKind::Money => {}
+        }
+    }
+}
+
+/// How many of the drop are given, a random amount between min and max
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct DropAmount {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for DropAmount {
+    fn default() -> Self {
+        Self { min: 1.0, max: 1.0 }
+    }
+}
+
+impl std::fmt::Display for DropAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.min == self.max {
+            write!(f, "{}", self.min)
+        } else {
+            write!(f, "{}-{}", self.min, self.max)
+        }
+    }
+}
+
+impl DropAmount {
+    fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Amount").on_hover_ui(|ui| {
+            ui.label("A random amount between these values is dropped");
+        });
+        ui.add(egui::DragValue::new(&mut self.min).range(0.0..=f32::MAX));
+        ui.label("to");
+        ui.add(egui::DragValue::new(&mut self.max).range(self.min..=f32::MAX));
+    }
+}
@@ -0,0 +1,369 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::skill_list::SkillList;
+use crate::{
+    export::yaml_quote,
+    skill::{Skill, ui::optional_value},
+};
+
+/// A Mythic item of the project, written in the `Items/` folder of the pack
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Item {
+    pub name: String,
+    /// The material of the item, ex: DIAMOND_SWORD
+    pub id: String,
+    pub display: String,
+    pub lore: Vec<String>,
+    pub enchantments: Vec<Enchantment>,
+    pub attributes: Vec<ItemAttribute>,
+    pub options: ItemOptions,
+    pub hide: Vec<HideFlag>,
+    pub custom_model_data: Option<u32>,
+    /// Skills of the item, they need the Crucible addon
+    pub skills: Vec<Skill>,
+    pub selected_skill: Option<usize>,
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            id: "DIAMOND_SWORD".to_owned(),
+            display: String::new(),
+            lore: Vec::new(),
+            enchantments: Vec::new(),
+            attributes: Vec::new(),
+            options: ItemOptions::default(),
+            hide: Vec::new(),
+            custom_model_data: None,
+            skills: Vec::new(),
+            selected_skill: None,
+        }
+    }
+}
+
+impl SkillList for Item {
+    fn skills(&mut self) -> &mut Vec<Skill> {
+        &mut self.skills
+    }
+    fn selected_skill(&mut self) -> &mut Option<usize> {
+        &mut self.selected_skill
+    }
+}
+
+/// An enchantment of the item, ex: `SHARPNESS:5`
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Enchantment {
+    pub name: String,
+    pub level: u32,
+}
+
+impl Default for Enchantment {
+    fn default() -> Self {
+        Self {
+            name: "SHARPNESS".to_owned(),
+            level: 1,
+        }
+    }
+}
+
+impl std::fmt::Display for Enchantment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.name, self.level)
+    }
+}
+
+/// An attribute modifier given while the item is in `slot`
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemAttribute {
+    pub slot: AttributeSlot,
+    pub attribute: AttributeKind,
+    pub value: f32,
+}
+
+#[derive(
+    Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
+pub enum AttributeSlot {
+    All,
+    #[default]
+    MainHand,
+    OffHand,
+    Head,
+    Chest,
+    Legs,
+    Feet,
+}
+
+#[derive(
+    Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
+pub enum AttributeKind {
+    #[default]
+    Damage,
+    AttackSpeed,
+    Armor,
+    ArmorToughness,
+    Health,
+    KnockbackResistance,
+    MovementSpeed,
+    Luck,
+}
+
+/// The `Options:` section of an item
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemOptions {
+    pub unbreakable: bool,
+    /// Color of leather armor and potions, as R,G,B
+    pub color: String,
+    /// Keep the item from stacking with identical items
+    pub prevent_stacking: bool,
+}
+
+/// An item flag listed in the `Hide:` section
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter)]
+pub enum HideFlag {
+    #[strum(to_string = "ENCHANTS")]
+    Enchants,
+    #[strum(to_string = "ATTRIBUTES")]
+    Attributes,
+    #[strum(to_string = "UNBREAKABLE")]
+    Unbreakable,
+    #[strum(to_string = "DESTROYS")]
+    Destroys,
+    #[strum(to_string = "PLACED_ON")]
+    PlacedOn,
+    #[strum(to_string = "POTION_EFFECTS")]
+    PotionEffects,
+    #[strum(to_string = "DYE")]
+    Dye,
+}
+
+impl Item {
+    /// Replace the whitespaces to "_" and removes \n
+    pub fn sanitize_name(&mut self) {
+        if self.name.is_empty() {
+            self.name = "Default_Item_Name".into();
+        }
+        self.name = self.name.trim().replace(" ", "_");
+    }
+    /// Generate the yaml section of this item
+    pub fn create_config(&mut self) -> String {
+        self.sanitize_name();
+        let mut config = format!("{}:\n  Id: {}", self.name, self.id);
+        if !self.display.is_empty() {
+            config.push_str(&format!("\n  Display: {}", yaml_quote(&self.display)));
+        }
+        if !self.lore.is_empty() {
+            config.push_str("\n  Lore:");
+            self.lore.iter().for_each(|line| {
+                config.push_str(&format!("\n  - {}", yaml_quote(line)));
+            });
+        }
+        if !self.enchantments.is_empty() {
+            config.push_str("\n  Enchantments:");
+            self.enchantments.iter().for_each(|enchantment| {
+                config.push_str(&format!("\n  - {enchantment}"));
+            });
+        }
+        if !self.attributes.is_empty() {
+            config.push_str("\n  Attributes:");
+            AttributeSlot::iter().for_each(|slot| {
+                let attributes: Vec<&ItemAttribute> =
+                    self.attributes.iter().filter(|a| a.slot == slot).collect();
+                if attributes.is_empty() {
+                    return;
+                }
+                config.push_str(&format!("\n    {slot}:"));
+                attributes.iter().for_each(|a| {
+                    config.push_str(&format!("\n      {}: {}", a.attribute, a.value));
+                });
+            });
+        }
+        config.push_str(&self.options.create_config());
+        if !self.hide.is_empty() {
+            config.push_str("\n  Hide:");
+            self.hide.iter().for_each(|flag| {
+                config.push_str(&format!("\n  - {flag}"));
+            });
+        }
+        if let Some(data) = self.custom_model_data {
+            config.push_str(&format!("\n  CustomModelData: {data}"));
+        }
+        let lines: Vec<String> = self
+            .skills
+            .iter()
+            .filter_map(|sk| sk.config_line(true))
+            .collect();
+        if !lines.is_empty() {
+            config.push_str("\n  Skills:");
+            lines.iter().for_each(|line| {
+                config.push_str(&format!("\n  {line}"));
+            });
+        }
+        config
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Internal Name").on_hover_ui(|ui| {
+                ui.label("The name used to reference the item in mobs, drops and mechanics");
+            });
+            ui.text_edit_singleline(&mut self.name);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Id").on_hover_ui(|ui| {
+                ui.label("The material of the item, ex: DIAMOND_SWORD");
+            });
+            ui.text_edit_singleline(&mut self.id);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Display").on_hover_ui(|ui| {
+                ui.label("The name of the item, supports color codes");
+            });
+            ui.text_edit_singleline(&mut self.display);
+        });
+        ui.horizontal(|ui| {
+            optional_value(
+                ui,
+                "Custom Model Data",
+                "Select the model of a resource pack",
+                &mut self.custom_model_data,
+                || 1,
+                |ui, data| {
+                    ui.add(egui::DragValue::new(data));
+                },
+            );
+        });
+        egui::CollapsingHeader::new(format!("Lore ({})", self.lore.len()))
+            .id_salt("Item_Lore")
+            .show(ui, |ui| {
+                list_ui(ui, &mut self.lore, "Add Line", |ui, line, _| {
+                    ui.text_edit_singleline(line);
+                });
+            });
+        egui::CollapsingHeader::new(format!("Enchantments ({})", self.enchantments.len()))
+            .id_salt("Item_Enchantments")
+            .show(ui, |ui| {
+                list_ui(
+                    ui,
+                    &mut self.enchantments,
+                    "Add Enchantment",
+                    |ui, enchantment, _| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut enchantment.name).desired_width(120.0),
+                        );
+                        ui.label("Level");
+                        ui.add(egui::DragValue::new(&mut enchantment.level));
+                    },
+                );
+            });
+        egui::CollapsingHeader::new(format!("Attributes ({})", self.attributes.len()))
+            .id_salt("Item_Attributes")
+            .show(ui, |ui| {
+                list_ui(
+                    ui,
+                    &mut self.attributes,
+                    "Add Attribute",
+                    |ui, attribute, id| {
+                        egui::ComboBox::new(("item_attribute_slot", id), "")
+                            .selected_text(attribute.slot.to_string())
+                            .show_ui(ui, |ui| {
+                                AttributeSlot::iter().for_each(|v| {
+                                    ui.selectable_value(&mut attribute.slot, v, v.to_string());
+                                });
+                            });
+                        egui::ComboBox::new(("item_attribute_kind", id), "")
+                            .selected_text(attribute.attribute.to_string())
+                            .show_ui(ui, |ui| {
+                                AttributeKind::iter().for_each(|v| {
+                                    ui.selectable_value(&mut attribute.attribute, v, v.to_string());
+                                });
+                            });
+                        ui.add(egui::DragValue::new(&mut attribute.value).speed(0.1));
+                    },
+                );
+            });
+        egui::CollapsingHeader::new("Options")
+            .id_salt("Item_Options")
+            .show(ui, |ui| {
+                self.options.handle_ui(ui);
+            });
+        egui::CollapsingHeader::new(format!("Hide ({})", self.hide.len()))
+            .id_salt("Item_Hide")
+            .show(ui, |ui| {
+                HideFlag::iter().for_each(|flag| {
+                    let mut hidden = self.hide.contains(&flag);
+                    if ui.checkbox(&mut hidden, flag.to_string()).changed() {
+                        if hidden {
+                            self.hide.push(flag);
+                        } else {
+                            self.hide.retain(|f| *f != flag);
+                        }
+                    }
+                });
+            });
+    }
+}
+
+impl ItemOptions {
+    /// The `Options:` section, empty when every option has its default value
+    fn create_config(&self) -> String {
+        let mut options = String::new();
+        if self.unbreakable {
+            options.push_str("\n    Unbreakable: true");
+        }
+        if !self.color.trim().is_empty() {
+            options.push_str(&format!("\n    Color: {}", self.color.trim()));
+        }
+        if self.prevent_stacking {
+            options.push_str("\n    PreventStacking: true");
+        }
+        if options.is_empty() {
+            return options;
+        }
+        format!("\n  Options:{options}")
+    }
+
+    fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.unbreakable, "Unbreakable");
+        ui.horizontal(|ui| {
+            ui.label("Color").on_hover_ui(|ui| {
+                ui.label("Color of leather armor and potions, as R,G,B");
+            });
+            ui.add(egui::TextEdit::singleline(&mut self.color).hint_text("255,0,0"));
+        });
+        ui.checkbox(&mut self.prevent_stacking, "Prevent Stacking")
+            .on_hover_ui(|ui| {
+                ui.label("Keep the item from stacking with identical items");
+            });
+    }
+}
+
+/// Editable list with a remove button on each row
+fn list_ui<T: Default>(
+    ui: &mut egui::Ui,
+    list: &mut Vec<T>,
+    add_label: &str,
+    row_ui: impl Fn(&mut egui::Ui, &mut T, usize),
+) {
+    let mut removed = None;
+    list.iter_mut().enumerate().for_each(|(id, entry)| {
+        ui.horizontal(|ui| {
+            row_ui(ui, entry, id);
+            if ui.button("Remove").clicked() {
+                removed = Some(id);
+            }
+        });
+    });
+    if let Some(id) = removed {
+        list.remove(id);
+    }
+    if ui.button(add_label).clicked() {
+        list.push(T::default());
+    }
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use super::skill_list::SkillList;
+use crate::skill::{Skill, conditions::SkillCondition};
+
+/// A metaskill of the project, written in the `Skills/` folder of the pack
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetaSkill {
+    pub name: String,
+    pub selected_skill: Option<usize>,
+    pub skills: Vec<Skill>,
+    /// Seconds before the metaskill can be cast again
+    pub cooldown: f32,
+    /// Don't cast the metaskill when its targeter finds no target
+    pub cancel_if_no_targets: bool,
+    /// Metaskill cast instead while this one is on cooldown
+    pub on_cooldown_skill: String,
+    /// Metaskill cast instead when the conditions are not met
+    pub failed_conditions_skill: String,
+    /// Fire the spell cast events for other plugins
+    pub spell: bool,
+    /// Conditions checked on the caster
+    pub conditions: Vec<SkillCondition>,
+    /// Conditions checked on each target
+    pub target_conditions: Vec<SkillCondition>,
+    /// Conditions checked on the entity that triggered the skill
+    pub trigger_conditions: Vec<SkillCondition>,
+}
+
+impl Default for MetaSkill {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            selected_skill: None,
+            skills: Vec::new(),
+            cooldown: 0.0,
+            cancel_if_no_targets: true,
+            on_cooldown_skill: String::new(),
+            failed_conditions_skill: String::new(),
+            spell: false,
+            conditions: Vec::new(),
+            target_conditions: Vec::new(),
+            trigger_conditions: Vec::new(),
+        }
+    }
+}
+
+impl SkillList for MetaSkill {
+    fn skills(&mut self) -> &mut Vec<Skill> {
+        &mut self.skills
+    }
+    fn selected_skill(&mut self) -> &mut Option<usize> {
+        &mut self.selected_skill
+    }
+}
+
+impl MetaSkill {
+    /// Replace the whitespaces to "_" and removes \n
+    pub fn sanitize_name(&mut self) {
+        if self.name.is_empty() {
+            self.name = "Default_Skill_Name".into();
+        }
+        self.name = self.name.trim().replace(" ", "_");
+    }
+    /// Generate the yaml section of this metaskill, keys in the order of the MythicMobs docs
+    pub fn create_config(&mut self) -> String {
+        self.sanitize_name();
+        let mut config = format!("{}:", self.name);
+        if self.cooldown > 0.0 {
+            config.push_str(&format!("\n  Cooldown: {}", self.cooldown));
+        }
+        if !self.cancel_if_no_targets {
+            config.push_str("\n  CancelIfNoTargets: false");
+        }
+        if !self.on_cooldown_skill.is_empty() {
+            config.push_str(&format!("\n  OnCooldownSkill: {}", self.on_cooldown_skill));
+        }
+        if !self.failed_conditions_skill.is_empty() {
+            config.push_str(&format!(
+                "\n  FailedConditionsSkill: {}",
+                self.failed_conditions_skill
+            ));
+        }
+        if self.spell {
+            config.push_str("\n  Spell: true");
+        }
+        [
+            ("Conditions", &self.conditions),
+            ("TargetConditions", &self.target_conditions),
+            ("TriggerConditions", &self.trigger_conditions),
+        ]
+        .iter()
+        .filter(|(_, conditions)| !conditions.is_empty())
+        .for_each(|(section, conditions)| {
+            config.push_str(&format!("\n  {section}:"));
+            conditions.iter().for_each(|c| {
+                config.push_str(&format!("\n  {c}"));
+            });
+        });
+        // Triggers only belong to mob skill lists
+        let lines: Vec<String> = self
+            .skills
+            .iter()
+            .filter_map(|sk| sk.config_line(false))
+            .collect();
+        if !lines.is_empty() {
+            config.push_str("\n  Skills:");
+            lines.iter().for_each(|line| {
+                config.push_str(&format!("\n  {line}"));
+            });
+        }
+        config
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ai_selectors::{AIGoalSelector, AITargetSelector, selectors_config},
+    boss_bar::BossBar,
+    damage_modifiers::{DamageModifier, damage_modifiers_config},
+    droptable::{DropLine, drops_config},
+    mob_equipment::MobEquipment,
+    mob_options::MobOptions,
+    skill_list::SkillList,
+};
+use crate::{
+    export::yaml_quote,
+    skill::{Skill, minecraft_lib::MinecraftMob},
+};
+
+/// A mob of the project
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mob {
+    pub mob_name: String,
+    pub mob_display_name: String,
+    pub mob_type: MinecraftMob,
+    pub mob_health: u32,
+    pub damage: u8,
+    pub armor: u8,
+    pub options: MobOptions,
+    pub equipment: MobEquipment,
+    pub drops: Vec<DropLine>,
+    pub damage_modifiers: Vec<DamageModifier>,
+    pub ai_goal_selectors: Vec<AIGoalSelector>,
+    pub ai_target_selectors: Vec<AITargetSelector>,
+    pub boss_bar: BossBar,
+    pub selected_skill: Option<usize>,
+    pub skills: Vec<Skill>,
+}
+
+impl SkillList for Mob {
+    fn skills(&mut self) -> &mut Vec<Skill> {
+        &mut self.skills
+    }
+    fn selected_skill(&mut self) -> &mut Option<usize> {
+        &mut self.selected_skill
+    }
+}
+
+impl Mob {
+    /// Replace the whitespaces to "_" and removes \n
+    pub fn sanitize_mob_name(&mut self) {
+        if self.mob_name.is_empty() {
+            self.mob_name = "Default_Mob_Name".into();
+        }
+        self.mob_name = self.mob_name.trim().replace(" ", "_");
+    }
+    /// Generate the yaml section of this mob
+    pub fn create_config(&mut self) -> String {
+        self.sanitize_mob_name();
+        format!(
+            "{}:\n  Type: {}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
+            self.mob_name,
+            self.mob_type,
+            if self.mob_display_name.is_empty() {
+                ""
+            } else {
+                "\n  Display: "
+            },
+            if self.mob_display_name.is_empty() {
+                String::new()
+            } else {
+                yaml_quote(&self.mob_display_name)
+            },
+            if self.mob_health == 0 {
+                ""
+            } else {
+                "\n  Health: "
+            },
+            if self.mob_health == 0 {
+                "".to_owned()
+            } else {
+                self.mob_health.to_string()
+            },
+            if self.damage == 0 { "" } else { "\n  Damage: " },
+            if self.damage == 0 {
+                "".to_owned()
+            } else {
+                self.damage.to_string()
+            },
+            if self.armor == 0 { "" } else { "\n  Armor: " },
+            if self.armor == 0 {
+                "".to_owned()
+            } else {
+                self.armor.to_string()
+            },
+            self.boss_bar.create_config(),
+            self.options.create_config(&self.mob_type),
+            self.equipment.create_config(),
+            drops_config(&self.drops),
+            damage_modifiers_config(&self.damage_modifiers),
+            selectors_config("AIGoalSelectors", &self.ai_goal_selectors),
+            selectors_config("AITargetSelectors", &self.ai_target_selectors),
+            if self.skills.is_empty() {
+                ""
+            } else {
+                "\n  Skills:"
+            },
+            // SKILLS
+            self.handle_skill_formating(),
+        )
+    }
+    pub fn handle_skill_formating(&self) -> String {
+        let mut skills_fmt = String::new();
+        self.skills.iter().for_each(|sk| {
+            if let Some(line) = sk.config_line(true) {
+                skills_fmt.push_str(&format!("\n  {line}"));
+            }
+        });
+        skills_fmt
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::skill::{mechanics::EquipmentItem, ui::optional_chance};
+
+/// The `Equipment:` section of a mob
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MobEquipment {
+    pub items: Vec<EquippedItem>,
+}
+
+/// An item worn by the mob, written as `- item:SLOT`
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EquippedItem {
+    pub item: EquipmentItem,
+    /// Chance for the item to drop when the mob dies, between 0 and 1
+    pub drop_chance: Option<f32>,
+}
+
+impl std::fmt::Display for EquippedItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.drop_chance {
+            Some(chance) => write!(f, "- {} {chance}", self.item),
+            None => write!(f, "- {}", self.item),
+        }
+    }
+}
+
+impl MobEquipment {
+    /// The `Equipment:` section, empty when the mob wears nothing
+    pub fn create_config(&self) -> String {
+        if self.items.is_empty() {
+            return String::new();
+        }
+        let mut equipment = String::from("\n  Equipment:");
+        self.items.iter().for_each(|item| {
+            equipment.push_str(&format!("\n  {item}"));
+        });
+        equipment
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        let mut removed = None;
+        self.items
+            .iter_mut()
+            .enumerate()
+            .for_each(|(id, equipped)| {
+                ui.horizontal(|ui| {
+                    equipped.item.handle_ui(ui, ("mob_equipment", id));
+                    optional_chance(
+                        ui,
+                        "Drop Chance",
+                        "Chance for the item to drop when the mob dies",
+                        &mut equipped.drop_chance,
+                    );
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+        if let Some(id) = removed {
+            self.items.remove(id);
+        }
+        if ui.button("Add Item").clicked() {
+            self.items.push(EquippedItem::default());
+        }
+    }
+}
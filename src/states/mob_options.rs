@@ -0,0 +1,563 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    export::yaml_quote,
+    skill::{minecraft_lib::MinecraftMob, ui::optional_number},
+};
+
+/// The `Options:` section of a mob, only the values that differ from the
+/// MythicMobs defaults are written
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MobOptions {
+    /// Base movement speed, the vanilla value of the entity type when None
+    pub movement_speed: Option<f32>,
+    /// Range in blocks the mob finds and follows targets in
+    pub follow_range: Option<f32>,
+    /// Resistance to knockback, from 0 to 1
+    pub knockback_resistance: Option<f32>,
+    /// Attacks per second
+    pub attack_speed: Option<f32>,
+    pub always_show_name: bool,
+    pub show_health: bool,
+    pub despawn: Despawn,
+    pub persistent: bool,
+    pub prevent_other_drops: bool,
+    pub prevent_random_equipment: bool,
+    pub prevent_item_pickup: bool,
+    pub prevent_leashing: bool,
+    pub prevent_renaming: bool,
+    pub prevent_sunburn: bool,
+    pub silent: bool,
+    pub invincible: bool,
+    pub invisible: bool,
+    pub collidable: bool,
+    pub interactable: bool,
+    pub no_ai: bool,
+    pub no_gravity: bool,
+    pub glowing: bool,
+    pub lock_pitch: bool,
+    pub repeat_all_skills: bool,
+    /// Ticks of invulnerability after being hit
+    pub no_damage_ticks: u32,
+    /// Options of specific entity types, see MinecraftMob::entity_options
+    pub entity_options: Vec<EntityOption>,
+}
+
+impl Default for MobOptions {
+    fn default() -> Self {
+        Self {
+            movement_speed: None,
+            follow_range: None,
+            knockback_resistance: None,
+            attack_speed: None,
+            always_show_name: false,
+            show_health: false,
+            despawn: Despawn::default(),
+            persistent: false,
+            prevent_other_drops: false,
+            prevent_random_equipment: false,
+            prevent_item_pickup: true,
+            prevent_leashing: true,
+            prevent_renaming: true,
+            prevent_sunburn: false,
+            silent: false,
+            invincible: false,
+            invisible: false,
+            collidable: true,
+            interactable: true,
+            no_ai: false,
+            no_gravity: false,
+            glowing: false,
+            lock_pitch: false,
+            repeat_all_skills: false,
+            no_damage_ticks: 10,
+            entity_options: Vec::new(),
+        }
+    }
+}
+
+/// When the mob is allowed to despawn
+#[derive(Clone, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter, Default)]
+pub enum Despawn {
+    #[strum(to_string = "true")]
+    #[default]
+    /// Like vanilla mobs, when no player is around
+    True,
+    #[strum(to_string = "false")]
+    /// Never despawns on its own, but is removed when its chunk unloads
+    False,
+    #[strum(to_string = "NEVER")]
+    /// Never despawns, even when its chunk unloads
+    Never,
+    #[strum(to_string = "PERSISTENT")]
+    /// Never despawns and keeps its data across restarts
+    Persistent,
+    #[strum(to_string = "CHUNK")]
+    /// Despawns when its chunk unloads
+    Chunk,
+}
+
+impl MobOptions {
+    /// The boolean options, with their yaml key
+    fn flags_mut(&mut self) -> [(&'static str, &mut bool); 19] {
+        [
+            ("AlwaysShowName", &mut self.always_show_name),
+            ("ShowHealth", &mut self.show_health),
+            ("Persistent", &mut self.persistent),
+            ("PreventOtherDrops", &mut self.prevent_other_drops),
+            ("PreventRandomEquipment", &mut self.prevent_random_equipment),
+            ("PreventItemPickup", &mut self.prevent_item_pickup),
+            ("PreventLeashing", &mut self.prevent_leashing),
+            ("PreventRenaming", &mut self.prevent_renaming),
+            ("PreventSunburn", &mut self.prevent_sunburn),
+            ("Silent", &mut self.silent),
+            ("Invincible", &mut self.invincible),
+            ("Invisible", &mut self.invisible),
+            ("Collidable", &mut self.collidable),
+            ("Interactable", &mut self.interactable),
+            ("NoAI", &mut self.no_ai),
+            ("NoGravity", &mut self.no_gravity),
+            ("Glowing", &mut self.glowing),
+            ("LockPitch", &mut self.lock_pitch),
+            ("RepeatAllSkills", &mut self.repeat_all_skills),
+        ]
+    }
+
+    /// The `Options:` section, empty when every option has its default value
+    pub fn create_config(&self, mob_type: &MinecraftMob) -> String {
+        let mut options = String::new();
+        let numbers = [
+            ("MovementSpeed", self.movement_speed),
+            ("FollowRange", self.follow_range),
+            ("KnockbackResistance", self.knockback_resistance),
+            ("AttackSpeed", self.attack_speed),
+        ];
+        numbers.iter().for_each(|(key, value)| {
+            if let Some(value) = value {
+                options.push_str(&format!("\n    {key}: {value}"));
+            }
+        });
+        if self.despawn != Despawn::default() {
+            options.push_str(&format!("\n    Despawn: {}", self.despawn));
+        }
+        let mut values = self.clone();
+        let mut defaults = MobOptions::default();
+        values
+            .flags_mut()
+            .into_iter()
+            .zip(defaults.flags_mut())
+            .filter(|((_, value), (_, default))| value != default)
+            .for_each(|((key, value), _)| {
+                options.push_str(&format!("\n    {key}: {value}"));
+            });
+        if self.no_damage_ticks != MobOptions::default().no_damage_ticks {
+            options.push_str(&format!("\n    NoDamageTicks: {}", self.no_damage_ticks));
+        }
+        let accepted = entity_options(mob_type);
+        self.entity_options
+            .iter()
+            .filter(|option| option.is_one_of(&accepted))
+            .filter_map(EntityOption::config_line)
+            .for_each(|line| {
+                options.push_str(&format!("\n    {line}"));
+            });
+        if options.is_empty() {
+            return options;
+        }
+        format!("\n  Options:{options}")
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, mob_type: &MinecraftMob) {
+        [
+            (
+                "Movement Speed",
+                "Base movement speed, the vanilla value of the entity type when unchecked",
+                &mut self.movement_speed,
+            ),
+            (
+                "Follow Range",
+                "Range in blocks the mob finds and follows targets in",
+                &mut self.follow_range,
+            ),
+            (
+                "Knockback Resistance",
+                "Resistance to knockback, from 0 to 1",
+                &mut self.knockback_resistance,
+            ),
+            ("Attack Speed", "Attacks per second", &mut self.attack_speed),
+        ]
+        .into_iter()
+        .for_each(|(label, hover, value)| {
+            ui.horizontal(|ui| optional_number(ui, label, hover, value, 0.01));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Despawn").on_hover_ui(|ui| {
+                ui.label("When the mob is allowed to despawn");
+            });
+            egui::ComboBox::new("Mob_Despawn", "")
+                .selected_text(self.despawn.to_string())
+                .show_ui(ui, |ui| {
+                    Despawn::iter().for_each(|v| {
+                        let label = v.to_string();
+                        ui.selectable_value(&mut self.despawn, v, label);
+                    });
+                });
+        });
+        ui.horizontal(|ui| {
+            ui.label("No Damage Ticks").on_hover_ui(|ui| {
+                ui.label("Ticks of invulnerability after being hit");
+            });
+            ui.add(egui::DragValue::new(&mut self.no_damage_ticks));
+        });
+        self.flags_mut().into_iter().for_each(|(key, value)| {
+            ui.checkbox(value, key);
+        });
+        let accepted = entity_options(mob_type);
+        if accepted.is_empty() {
+            return;
+        }
+        ui.separator();
+        ui.label(format!("{mob_type} Options"));
+        accepted.into_iter().for_each(|default| {
+            ui.horizontal(|ui| {
+                let position = self
+                    .entity_options
+                    .iter()
+                    .position(|o| o.is_one_of(std::slice::from_ref(&default)));
+                let mut enabled = position.is_some();
+                ui.checkbox(&mut enabled, default.get_fields().into())
+                    .on_hover_ui(|ui| {
+                        ui.label(default.hover());
+                    });
+                match (enabled, position) {
+                    (true, Some(id)) => self.entity_options[id].handle_ui(ui),
+                    (true, None) => self.entity_options.push(default),
+                    (false, Some(id)) => {
+                        self.entity_options.remove(id);
+                    }
+                    (false, None) => {}
+                }
+            });
+        });
+    }
+}
+
+/// An option only some entity types accept, written as `Key: value` under `Options:`
+#[derive(Clone, PartialEq, Serialize, Deserialize, strum::Display)]
+pub enum EntityOption {
+    #[strum(to_string = "ExplosionRadius: {0}")]
+    ExplosionRadius(u32),
+    #[strum(to_string = "FuseTicks: {0}")]
+    FuseTicks(u32),
+    #[strum(to_string = "SuperCharged: {0}")]
+    SuperCharged(bool),
+    #[strum(to_string = "PreventSuicide: {0}")]
+    PreventSuicide(bool),
+    #[strum(to_string = "Size: {0}")]
+    Size(u32),
+    #[strum(to_string = "PreventSlimeSplit: {0}")]
+    PreventSlimeSplit(bool),
+    #[strum(to_string = "HorseColor: {0}")]
+    HorseColor(String),
+    #[strum(to_string = "HorseStyle: {0}")]
+    HorseStyle(String),
+    #[strum(to_string = "HorseArmor: {0}")]
+    HorseArmor(String),
+    #[strum(to_string = "HorseSaddled: {0}")]
+    HorseSaddled(bool),
+    #[strum(to_string = "HorseTamed: {0}")]
+    HorseTamed(bool),
+    #[strum(to_string = "CarryingChest: {0}")]
+    CarryingChest(bool),
+    #[strum(to_string = "Profession: {0}")]
+    Profession(String),
+    #[strum(to_string = "VillagerType: {0}")]
+    VillagerType(String),
+    #[strum(to_string = "Marker: {0}")]
+    Marker(bool),
+    #[strum(to_string = "Small: {0}")]
+    Small(bool),
+    #[strum(to_string = "HasArms: {0}")]
+    HasArms(bool),
+    #[strum(to_string = "HasBasePlate: {0}")]
+    HasBasePlate(bool),
+    #[strum(to_string = "Color: {0}")]
+    Color(String),
+    #[strum(to_string = "Sheared: {0}")]
+    Sheared(bool),
+    #[strum(to_string = "Angry: {0}")]
+    Angry(bool),
+    #[strum(to_string = "PreventTeleport: {0}")]
+    PreventTeleport(bool),
+    #[strum(to_string = "RabbitType: {0}")]
+    RabbitType(String),
+    #[strum(to_string = "Translation: {0}")]
+    Translation(String),
+    #[strum(to_string = "Scale: {0}")]
+    Scale(String),
+    #[strum(to_string = "Billboard: {0}")]
+    Billboard(String),
+    #[strum(to_string = "ViewRange: {0}")]
+    ViewRange(f32),
+    #[strum(to_string = "Text: {0}")]
+    Text(String),
+    #[strum(to_string = "Block: {0}")]
+    Block(String),
+    #[strum(to_string = "Item: {0}")]
+    Item(String),
+}
+
+impl EntityOption {
+    pub fn get_fields(&self) -> impl Into<String> {
+        match self {
+            EntityOption::ExplosionRadius(_) => "Explosion Radius",
+            EntityOption::FuseTicks(_) => "Fuse Ticks",
+            EntityOption::SuperCharged(_) => "Super Charged",
+            EntityOption::PreventSuicide(_) => "Prevent Suicide",
+            EntityOption::Size(_) => "Size",
+            EntityOption::PreventSlimeSplit(_) => "Prevent Slime Split",
+            EntityOption::HorseColor(_) => "Horse Color",
+            EntityOption::HorseStyle(_) => "Horse Style",
+            EntityOption::HorseArmor(_) => "Horse Armor",
+            EntityOption::HorseSaddled(_) => "Horse Saddled",
+            EntityOption::HorseTamed(_) => "Horse Tamed",
+            EntityOption::CarryingChest(_) => "Carrying Chest",
+            EntityOption::Profession(_) => "Profession",
+            EntityOption::VillagerType(_) => "Villager Type",
+            EntityOption::Marker(_) => "Marker",
+            EntityOption::Small(_) => "Small",
+            EntityOption::HasArms(_) => "Has Arms",
+            EntityOption::HasBasePlate(_) => "Has Base Plate",
+            EntityOption::Color(_) => "Color",
+            EntityOption::Sheared(_) => "Sheared",
+            EntityOption::Angry(_) => "Angry",
+            EntityOption::PreventTeleport(_) => "Prevent Teleport",
+            EntityOption::RabbitType(_) => "Rabbit Type",
+            EntityOption::Translation(_) => "Translation",
+            EntityOption::Scale(_) => "Scale",
+            EntityOption::Billboard(_) => "Billboard",
+            EntityOption::ViewRange(_) => "View Range",
+            EntityOption::Text(_) => "Text",
+            EntityOption::Block(_) => "Block",
+            EntityOption::Item(_) => "Item",
+        }
+    }
+
+    fn hover(&self) -> &'static str {
+        match self {
+            EntityOption::ExplosionRadius(_) => "The radius of the creeper explosion",
+            EntityOption::FuseTicks(_) => "Ticks before the creeper explodes",
+            EntityOption::SuperCharged(_) => "Whether the creeper is charged",
+            EntityOption::PreventSuicide(_) => "Keep the creeper alive after it explodes",
+            EntityOption::Size(_) => "The size of the slime, magma cube or phantom",
+            EntityOption::PreventSlimeSplit(_) => "Don't split into smaller slimes on death",
+            EntityOption::HorseColor(_) => {
+                "WHITE, CREAMY, CHESTNUT, BROWN, BLACK, GRAY or DARK_BROWN"
+            }
+            EntityOption::HorseStyle(_) => "NONE, WHITE, WHITEFIELD, WHITE_DOTS or BLACK_DOTS",
+            EntityOption::HorseArmor(_) => "The armor material, ex: DIAMOND",
+            EntityOption::HorseSaddled(_) => "Whether the horse wears a saddle",
+            EntityOption::HorseTamed(_) => "Whether the horse is tamed",
+            EntityOption::CarryingChest(_) => "Whether the donkey, mule or llama carries a chest",
+            EntityOption::Profession(_) => "The villager profession, ex: ARMORER, FARMER, NITWIT",
+            EntityOption::VillagerType(_) => "The villager biome type, ex: PLAINS, DESERT, TAIGA",
+            EntityOption::Marker(_) => "Make the armor stand a marker without hitbox",
+            EntityOption::Small(_) => "Make the armor stand small",
+            EntityOption::HasArms(_) => "Show the arms of the armor stand",
+            EntityOption::HasBasePlate(_) => "Show the base plate of the armor stand",
+            EntityOption::Color(_) => "The wool color of the sheep, ex: RED",
+            EntityOption::Sheared(_) => "Whether the sheep is sheared",
+            EntityOption::Angry(_) => "Whether the wolf is angry",
+            EntityOption::PreventTeleport(_) => "Prevent the enderman from teleporting",
+            EntityOption::RabbitType(_) => "The rabbit type, ex: BROWN, THE_KILLER_BUNNY",
+            EntityOption::Translation(_) => "The offset of the display, as x,y,z",
+            EntityOption::Scale(_) => "The scale of the display, as x,y,z",
+            EntityOption::Billboard(_) => "FIXED, VERTICAL, HORIZONTAL or CENTER",
+            EntityOption::ViewRange(_) => "The view range multiplier of the display",
+            EntityOption::Text(_) => "The text of the text display",
+            EntityOption::Block(_) => "The block of the block display",
+            EntityOption::Item(_) => "The item of the item display",
+        }
+    }
+
+    /// The value of the text options, None for the number and boolean options
+    fn text(&self) -> Option<&str> {
+        match self {
+            EntityOption::HorseColor(value)
+            | EntityOption::HorseStyle(value)
+            | EntityOption::HorseArmor(value)
+            | EntityOption::Profession(value)
+            | EntityOption::VillagerType(value)
+            | EntityOption::Color(value)
+            | EntityOption::RabbitType(value)
+            | EntityOption::Translation(value)
+            | EntityOption::Scale(value)
+            | EntityOption::Billboard(value)
+            | EntityOption::Text(value)
+            | EntityOption::Block(value)
+            | EntityOption::Item(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The `Key: value` line of the option, text values are quoted and an
+    /// empty text is not written
+    pub fn config_line(&self) -> Option<String> {
+        match self.text() {
+            None => Some(self.to_string()),
+            Some(text) if text.trim().is_empty() => None,
+            Some(text) => {
+                let line = self.to_string();
+                let (key, _) = line.split_once(':')?;
+                Some(format!("{key}: {}", yaml_quote(text)))
+            }
+        }
+    }
+
+    /// Whether this is one of the given options, whatever its value
+    pub fn is_one_of(&self, options: &[EntityOption]) -> bool {
+        options
+            .iter()
+            .any(|o| std::mem::discriminant(o) == std::mem::discriminant(self))
+    }
+
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui) {
+        match self {
+            EntityOption::ExplosionRadius(value)
+            | EntityOption::FuseTicks(value)
+            | EntityOption::Size(value) => {
+                ui.add(egui::DragValue::new(value));
+            }
+            EntityOption::ViewRange(value) => {
+                ui.add(egui::DragValue::new(value).speed(0.1));
+            }
+            EntityOption::SuperCharged(value)
+            | EntityOption::PreventSuicide(value)
+            | EntityOption::PreventSlimeSplit(value)
+            | EntityOption::HorseSaddled(value)
+            | EntityOption::HorseTamed(value)
+            | EntityOption::CarryingChest(value)
+            | EntityOption::Marker(value)
+            | EntityOption::Small(value)
+            | EntityOption::HasArms(value)
+            | EntityOption::HasBasePlate(value)
+            | EntityOption::Sheared(value)
+            | EntityOption::Angry(value)
+            | EntityOption::PreventTeleport(value) => {
+                ui.checkbox(value, "");
+            }
+            EntityOption::HorseColor(value)
+            | EntityOption::HorseStyle(value)
+            | EntityOption::HorseArmor(value)
+            | EntityOption::Profession(value)
+            | EntityOption::VillagerType(value)
+            | EntityOption::Color(value)
+            | EntityOption::RabbitType(value)
+            | EntityOption::Translation(value)
+            | EntityOption::Scale(value)
+            | EntityOption::Billboard(value)
+            | EntityOption::Text(value)
+            | EntityOption::Block(value)
+            | EntityOption::Item(value) => {
+                ui.text_edit_singleline(value);
+            }
+        }
+    }
+}
+
+/// The options only this entity type accepts, with their default value
+fn entity_options(mob_type: &MinecraftMob) -> Vec<EntityOption> {
+    use EntityOption::*;
+    match mob_type {
+        MinecraftMob::Creeper => vec![
+            ExplosionRadius(3),
+            FuseTicks(30),
+            SuperCharged(false),
+            PreventSuicide(false),
+        ],
+        MinecraftMob::Slime | MinecraftMob::MagmaCube => {
+            vec![Size(1), PreventSlimeSplit(false)]
+        }
+        MinecraftMob::Phantom => vec![Size(1)],
+        MinecraftMob::Horse => vec![
+            HorseColor("WHITE".to_owned()),
+            HorseStyle("NONE".to_owned()),
+            HorseArmor("IRON".to_owned()),
+            HorseSaddled(false),
+            HorseTamed(false),
+        ],
+        MinecraftMob::SkeletonHorse | MinecraftMob::ZombieHorse => {
+            vec![HorseSaddled(false), HorseTamed(false)]
+        }
+        MinecraftMob::Donkey
+        | MinecraftMob::Mule
+        | MinecraftMob::Llama
+        | MinecraftMob::TraderLlama => {
+            vec![CarryingChest(false), HorseSaddled(false), HorseTamed(false)]
+        }
+        MinecraftMob::Villager | MinecraftMob::ZombieVillager => vec![
+            Profession("NONE".to_owned()),
+            VillagerType("PLAINS".to_owned()),
+        ],
+        MinecraftMob::ArmorStand => vec![
+            Marker(false),
+            Small(false),
+            HasArms(false),
+            HasBasePlate(true),
+        ],
+        MinecraftMob::Sheep => vec![Color("WHITE".to_owned()), Sheared(false)],
+        MinecraftMob::Wolf => vec![Angry(false)],
+        MinecraftMob::Enderman => vec![PreventTeleport(false)],
+        MinecraftMob::Rabbit => vec![RabbitType("BROWN".to_owned())],
+        MinecraftMob::BlockDisplay => {
+            let mut options = display_options();
+            options.push(Block("STONE".to_owned()));
+            options
+        }
+        MinecraftMob::ItemDisplay => {
+            let mut options = display_options();
+            options.push(Item("STONE".to_owned()));
+            options
+        }
+        MinecraftMob::TextDisplay => {
+            let mut options = display_options();
+            options.push(Text(String::new()));
+            options
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Transform options shared by the display entities
+fn display_options() -> Vec<EntityOption> {
+    vec![
+        EntityOption::Translation("0,0,0".to_owned()),
+        EntityOption::Scale("1,1,1".to_owned()),
+        EntityOption::Billboard("FIXED".to_owned()),
+        EntityOption::ViewRange(1.0),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_options_quote_text_and_skip_empty_text() {
+        let mut options = MobOptions {
+            entity_options: vec![
+                EntityOption::Text("&cBoss: #1".to_owned()),
+                EntityOption::ViewRange(2.0),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            options.create_config(&MinecraftMob::TextDisplay),
+            "\n  Options:\n    Text: '&cBoss: #1'\n    ViewRange: 2"
+        );
+        options.entity_options = vec![EntityOption::Text(String::new())];
+        assert_eq!(options.create_config(&MinecraftMob::TextDisplay), "");
+    }
+}
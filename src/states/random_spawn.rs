@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::skill::{conditions::SkillCondition, params::BiomeList};
+
+/// A random spawn of the project, written in the `RandomSpawns/` folder of the pack
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RandomSpawn {
+    pub name: String,
+    /// Internal name of the spawned mob
+    pub mob: String,
+    /// Comma separated worlds, every world when empty
+    pub worlds: String,
+    /// Biomes the mob spawns in, every biome when empty
+    pub biomes: BiomeList,
+    /// Chance for the spawn to happen, between 0 and 1
+    pub chance: f32,
+    /// Random spawns with a higher priority are checked first
+    pub priority: i32,
+    pub action: SpawnAction,
+    /// Seconds before the random spawn can happen again
+    pub cooldown: u32,
+    pub position_type: PositionType,
+    pub conditions: Vec<SkillCondition>,
+}
+
+impl Default for RandomSpawn {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            mob: String::new(),
+            worlds: String::new(),
+            biomes: BiomeList::default(),
+            chance: 0.1,
+            priority: 1,
+            action: SpawnAction::default(),
+            cooldown: 0,
+            position_type: PositionType::default(),
+            conditions: Vec::new(),
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
+pub enum SpawnAction {
+    /// Spawn the mob along with the vanilla spawn
+    #[default]
+    #[strum(to_string = "ADD")]
+    Add,
+    /// Spawn the mob instead of the vanilla spawn
+    #[strum(to_string = "REPLACE")]
+    Replace,
+}
+
+#[derive(
+    Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumIter,
+)]
+pub enum PositionType {
+    #[default]
+    #[strum(to_string = "LAND")]
+    Land,
+    #[strum(to_string = "SEA")]
+    Sea,
+}
+
+impl RandomSpawn {
+    /// Replace the whitespaces to "_" and removes \n
+    pub fn sanitize_name(&mut self) {
+        if self.name.is_empty() {
+            self.name = "Default_RandomSpawn_Name".into();
+        }
+        self.name = self.name.trim().replace(" ", "_");
+    }
+    /// Generate the yaml section of this random spawn, None when it has no mob
+    /// since MythicMobs rejects a random spawn without a type
+    pub fn create_config(&mut self) -> Option<String> {
+        self.sanitize_name();
+        if self.mob.trim().is_empty() {
+            return None;
+        }
+        let mut config = format!("{}:\n  Type: {}", self.name, self.mob.trim());
+        if !self.worlds.trim().is_empty() {
+            config.push_str(&format!("\n  Worlds: {}", self.worlds.replace(' ', "")));
+        }
+        let biomes: Vec<&str> = self.biomes.biomes().collect();
+        if !biomes.is_empty() {
+            config.push_str("\n  Biomes:");
+            biomes.iter().for_each(|biome| {
+                config.push_str(&format!("\n  - {biome}"));
+            });
+        }
+        config.push_str(&format!(
+            "\n  Chance: {}\n  Priority: {}\n  Action: {}",
+            self.chance, self.priority, self.action
+        ));
+        if self.cooldown > 0 {
+            config.push_str(&format!("\n  Cooldown: {}", self.cooldown));
+        }
+        config.push_str(&format!("\n  PositionType: {}", self.position_type));
+        if !self.conditions.is_empty() {
+            config.push_str("\n  Conditions:");
+            self.conditions.iter().for_each(|c| {
+                config.push_str(&format!("\n  {c}"));
+            });
+        }
+        Some(config)
+    }
+
+    /// `mobs` are the internal names offered for the spawned mob
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, mobs: &[String]) {
+        ui.horizontal(|ui| {
+            ui.label("Internal Name").on_hover_ui(|ui| {
+                ui.label("The name of the random spawn, it must be unique");
+            });
+            ui.text_edit_singleline(&mut self.name);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Type").on_hover_ui(|ui| {
+                ui.label("Internal name of the spawned mob");
+            });
+            egui::ComboBox::new("Random_Spawn_Mob", "")
+                .selected_text(self.mob.clone())
+                .show_ui(ui, |ui| {
+                    mobs.iter().for_each(|mob| {
+                        ui.selectable_value(&mut self.mob, mob.clone(), mob);
+                    });
+                });
+            ui.text_edit_singleline(&mut self.mob);
+            if self.mob.trim().is_empty() {
+                ui.colored_label(egui::Color32::YELLOW, "⚠")
+                    .on_hover_text("Type is required, the random spawn is not exported without it");
+            } else if !mobs.contains(&self.mob) {
+                ui.colored_label(egui::Color32::YELLOW, "⚠")
+                    .on_hover_text("No mob of the project has this internal name");
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Worlds").on_hover_ui(|ui| {
+                ui.label("Comma separated worlds, every world when empty");
+            });
+            ui.add(egui::TextEdit::singleline(&mut self.worlds).hint_text("world,world_nether"));
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Biomes").on_hover_ui(|ui| {
+                ui.label("Biomes the mob spawns in, every biome when empty");
+            });
+            self.biomes.handle_ui(ui, "Random_Spawn_Biomes");
+        });
+        ui.horizontal(|ui| {
+            ui.label("Chance").on_hover_ui(|ui| {
+                ui.label("Chance for the spawn to happen");
+            });
+            ui.add(egui::Slider::new(&mut self.chance, 0.0..=1.0));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Priority").on_hover_ui(|ui| {
+                ui.label("Random spawns with a higher priority are checked first");
+            });
+            ui.add(egui::DragValue::new(&mut self.priority));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Cooldown").on_hover_ui(|ui| {
+                ui.label("Seconds before the random spawn can happen again, 0 for none");
+            });
+            ui.add(egui::DragValue::new(&mut self.cooldown));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Action").on_hover_ui(|ui| {
+                ui.label("ADD spawns the mob along with the vanilla spawn, REPLACE instead of it");
+            });
+            egui::ComboBox::new("Random_Spawn_Action", "")
+                .selected_text(self.action.to_string())
+                .show_ui(ui, |ui| {
+                    SpawnAction::iter().for_each(|v| {
+                        ui.selectable_value(&mut self.action, v, v.to_string());
+                    });
+                });
+            ui.label("Position Type");
+            egui::ComboBox::new("Random_Spawn_Position", "")
+                .selected_text(self.position_type.to_string())
+                .show_ui(ui, |ui| {
+                    PositionType::iter().for_each(|v| {
+                        ui.selectable_value(&mut self.position_type, v, v.to_string());
+                    });
+                });
+        });
+    }
+}
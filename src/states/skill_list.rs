@@ -0,0 +1,67 @@
+use crate::skill::Skill;
+
+/// The skill list shared by mobs and metaskills
+pub trait SkillList {
+    fn skills(&mut self) -> &mut Vec<Skill>;
+    fn selected_skill(&mut self) -> &mut Option<usize>;
+
+    /// Append a default skill to the list and select it
+    fn add_skill(&mut self) {
+        let name = format!("Skill_{}", self.skills().len());
+        self.skills().push(Skill::default().set_name(name));
+        self.select_last_skill();
+    }
+    fn get_selected_skill(&mut self) -> Option<&mut Skill> {
+        let id = (*self.selected_skill())?;
+        self.skills().get_mut(id)
+    }
+    /// Rename the selected skill
+    fn rename_selected_skill(&mut self, name: impl Into<String>)
+    where
+        Self: Sized,
+    {
+        if let Some(sk) = self.get_selected_skill() {
+            sk.rename(name);
+        }
+    }
+    /// Remove the selected skill from the list
+    fn remove_selected_skill(&mut self) {
+        if let Some(id) = *self.selected_skill() {
+            self.remove_skill(id);
+        }
+    }
+    /// set the selected skill to the first one in the list
+    /// if empty, set to None
+    fn select_first_skill(&mut self) {
+        if self.skills().is_empty() {
+            *self.selected_skill() = None;
+            return;
+        }
+        self.select_skill(0);
+    }
+    /// set the selected skill to the last one in the list
+    /// if empty, set to None
+    fn select_last_skill(&mut self) {
+        if self.skills().is_empty() {
+            *self.selected_skill() = None;
+            return;
+        }
+        let last = self.skills().len() - 1;
+        self.select_skill(last);
+    }
+    /// remove skill from the list at index
+    fn remove_skill(&mut self, id: usize) {
+        if id < self.skills().len() {
+            self.skills().remove(id);
+        }
+    }
+    fn select_skill(&mut self, id: usize) {
+        if self.skills().is_empty() {
+            *self.selected_skill() = None;
+            return;
+        }
+        if id < self.skills().len() {
+            *self.selected_skill() = Some(id);
+        }
+    }
+}
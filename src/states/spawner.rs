@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use crate::skill::conditions::SkillCondition;
+
+/// A spawner of the project, written in the `Spawners/` folder of the pack
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Spawner {
+    pub name: String,
+    /// Internal name of the spawned mob
+    pub mob_name: String,
+    pub world: String,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    /// Mobs spawn at a random location within this radius
+    pub radius: u32,
+    /// Maximum number of living mobs from this spawner
+    pub max_mobs: u32,
+    /// Seconds between two spawns
+    pub cooldown: u32,
+    /// Seconds before the first spawn once a player is in range
+    pub warmup: u32,
+    /// Group the spawner belongs to, empty for none
+    pub group: String,
+    /// Distance in blocks a player must be within for the spawner to work
+    pub activation_range: u32,
+    pub conditions: Vec<SkillCondition>,
+}
+
+impl Default for Spawner {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            mob_name: String::new(),
+            world: "world".to_owned(),
+            x: 0,
+            y: 64,
+            z: 0,
+            radius: 0,
+            max_mobs: 1,
+            cooldown: 0,
+            warmup: 0,
+            group: String::new(),
+            activation_range: 40,
+            conditions: Vec::new(),
+        }
+    }
+}
+
+impl Spawner {
+    /// Replace the whitespaces to "_" and removes \n
+    pub fn sanitize_name(&mut self) {
+        if self.name.is_empty() {
+            self.name = "Default_Spawner_Name".into();
+        }
+        self.name = self.name.trim().replace(" ", "_");
+    }
+    /// Generate the yaml section of this spawner, None when it has no mob since
+    /// MythicMobs rejects a spawner without a mob name
+    pub fn create_config(&mut self) -> Option<String> {
+        self.sanitize_name();
+        if self.mob_name.trim().is_empty() {
+            return None;
+        }
+        let mut config = format!(
+            "{}:\n  MobName: {}\n  World: {}",
+            self.name,
+            self.mob_name.trim(),
+            self.world.trim()
+        );
+        if !self.group.trim().is_empty() {
+            config.push_str(&format!("\n  SpawnerGroup: {}", self.group.trim()));
+        }
+        config.push_str(&format!(
+            "\n  X: {}\n  Y: {}\n  Z: {}\n  Radius: {}\n  MaxMobs: {}\n  Cooldown: {}\n  Warmup: {}\n  ActivationRange: {}",
+            self.x,
+            self.y,
+            self.z,
+            self.radius,
+            self.max_mobs,
+            self.cooldown,
+            self.warmup,
+            self.activation_range
+        ));
+        if !self.conditions.is_empty() {
+            config.push_str("\n  Conditions:");
+            self.conditions.iter().for_each(|c| {
+                config.push_str(&format!("\n  {c}"));
+            });
+        }
+        Some(config)
+    }
+
+    /// `mobs` are the internal names offered for the spawned mob
+    pub fn handle_ui(&mut self, ui: &mut egui::Ui, mobs: &[String]) {
+        ui.horizontal(|ui| {
+            ui.label("Internal Name").on_hover_ui(|ui| {
+                ui.label("The name used to reference the spawner in skills and commands");
+            });
+            ui.text_edit_singleline(&mut self.name);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Mob Name").on_hover_ui(|ui| {
+                ui.label("Internal name of the spawned mob");
+            });
+            egui::ComboBox::new("Spawner_Mob", "")
+                .selected_text(self.mob_name.clone())
+                .show_ui(ui, |ui| {
+                    mobs.iter().for_each(|mob| {
+                        ui.selectable_value(&mut self.mob_name, mob.clone(), mob);
+                    });
+                });
+            ui.text_edit_singleline(&mut self.mob_name);
+            if self.mob_name.trim().is_empty() {
+                ui.colored_label(egui::Color32::YELLOW, "⚠")
+                    .on_hover_text("Mob Name is required, the spawner is not exported without it");
+            } else if !mobs.contains(&self.mob_name) {
+                ui.colored_label(egui::Color32::YELLOW, "⚠")
+                    .on_hover_text("No mob of the project has this internal name");
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("World");
+            ui.text_edit_singleline(&mut self.world);
+        });
+        ui.horizontal(|ui| {
+            ui.label("X");
+            ui.add(egui::DragValue::new(&mut self.x));
+            ui.label("Y");
+            ui.add(egui::DragValue::new(&mut self.y));
+            ui.label("Z");
+            ui.add(egui::DragValue::new(&mut self.z));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Group").on_hover_ui(|ui| {
+                ui.label(
+                    "Group the spawner belongs to, spawners of a group can be activated together",
+                );
+            });
+            ui.text_edit_singleline(&mut self.group);
+        });
+        [
+            (
+                "Radius",
+                "Mobs spawn at a random location within this radius",
+                &mut self.radius,
+            ),
+            (
+                "Max Mobs",
+                "Maximum number of living mobs from this spawner",
+                &mut self.max_mobs,
+            ),
+            ("Cooldown", "Seconds between two spawns", &mut self.cooldown),
+            (
+                "Warmup",
+                "Seconds before the first spawn once a player is in range",
+                &mut self.warmup,
+            ),
+            (
+                "Activation Range",
+                "Distance in blocks a player must be within for the spawner to work",
+                &mut self.activation_range,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(label, hover, value)| {
+            ui.horizontal(|ui| {
+                ui.label(label).on_hover_ui(|ui| {
+                    ui.label(hover);
+                });
+                ui.add(egui::DragValue::new(value));
+            });
+        });
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    skill::{mechanics::{Mechanic, MythicOption, TradeIngredient}, minecraft_lib::MinecraftMob, targeters::{MultyEntity, SingleEntityTarget, Targeter}, triggers::Trigger},
-    states::AppState,
+    skill::{ProjectNames, conditions::SkillCondition, modifiers::HealthModifier, mechanics::{BulletType, Mechanic, MythicOption, ProjectileSkills, ShootType, SwitchCase, TradeIngredient, VariableScope, VariableType, WeightedSkill}, minecraft_lib::MinecraftMob, targeters::{MultyEntity, SingleEntityTarget, Targeter, ThreatTableTarget}, triggers::Trigger, ui::{optional_argument, optional_chance, optional_value}},
+    states::{AppState, Selection, damage_modifiers::DamageModifier, droptable::{DropLine, DropTable}, metaskill::MetaSkill, skill_list::SkillList},
 };
 use eframe::egui;
 use egui::{Button, Color32};
@@ -8,73 +8,355 @@ use strum::{IntoEnumIterator, VariantArray};
 
 pub struct Ui {
     pub state: AppState,
+    /// Path prompt opened by File > Open / File > Save As
+    pub file_prompt: Option<FilePrompt>,
+    /// Result of the last file operation
+    pub status: String,
+    /// What the project browser lists
+    pub browser_tab: BrowserTab,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BrowserTab {
+    /// Mobs, metaskills, droptables, spawners and random spawns
+    Project,
+    Items,
+}
+
+/// The file action waiting for a path to be entered
+pub enum FilePrompt {
+    Open(String),
+    SaveAs(String),
+    ImportMob(String),
+    /// Paths are edited in AppState::export
+    Export,
 }
 
 impl Ui {
     pub fn new(_cc: &eframe::CreationContext<'_>, state: AppState) -> Self {
-        Self { state }
+        Self {
+            state,
+            file_prompt: None,
+            status: String::new(),
+            browser_tab: BrowserTab::Project,
+        }
+    }
+    fn set_status(&mut self, result: Result<(), String>, success: impl Into<String>) {
+        self.status = match result {
+            Ok(()) => success.into(),
+            Err(e) => e,
+        };
+    }
+    fn file_menu(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("File", |ui| {
+            if ui.button("New").clicked() {
+                self.state.new_project();
+                self.status = "New project".to_owned();
+                ui.close();
+            }
+            if ui.button("Open...").clicked() {
+                self.file_prompt = Some(FilePrompt::Open(self.project_path_text()));
+                ui.close();
+            }
+            if ui.button("Save").clicked() {
+                if self.state.project_path.is_some() {
+                    let result = self.state.save_project();
+                    self.set_status(result, "Project saved");
+                } else {
+                    self.file_prompt = Some(FilePrompt::SaveAs(self.project_path_text()));
+                }
+                ui.close();
+            }
+            if ui.button("Save As...").clicked() {
+                self.file_prompt = Some(FilePrompt::SaveAs(self.project_path_text()));
+                ui.close();
+            }
+            ui.separator();
+            if ui.button("Import Mob YAML...").clicked() {
+                self.file_prompt = Some(FilePrompt::ImportMob("Mobs/".to_owned()));
+                ui.close();
+            }
+            if ui.button("Export to Pack...").clicked() {
+                self.file_prompt = Some(FilePrompt::Export);
+                ui.close();
+            }
+        });
+    }
+    /// List the entries of the project in the browser tab
+    fn browser_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let tab = self.browser_tab;
+            ui.selectable_value(&mut self.browser_tab, BrowserTab::Project, "Project");
+            ui.selectable_value(&mut self.browser_tab, BrowserTab::Items, "Items");
+            if self.browser_tab != tab {
+                self.state.selected = None;
+            }
+        });
+        ui.separator();
+        if self.browser_tab == BrowserTab::Items {
+            self.items_browser_ui(ui);
+            return;
+        }
+        ui.heading("Mobs");
+        if ui.add(Button::new("Add Mob")).clicked() {
+            self.state.add_mob();
+        }
+        self.state
+            .mobs
+            .iter()
+            .map(|mob| mob.mob_name.clone())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .enumerate()
+            .for_each(|(id, name)| self.browser_entry(ui, name, Selection::Mob(id)));
+        ui.separator();
+        ui.heading("MetaSkills");
+        if ui.add(Button::new("Add MetaSkill")).clicked() {
+            self.state.add_metaskill();
+        }
+        self.state
+            .metaskills
+            .iter()
+            .map(|metaskill| metaskill.name.clone())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .enumerate()
+            .for_each(|(id, name)| self.browser_entry(ui, name, Selection::MetaSkill(id)));
+        ui.separator();
+        ui.heading("DropTables");
+        if ui.add(Button::new("Add DropTable")).clicked() {
+            self.state.add_droptable();
+        }
+        self.state
+            .droptable_names()
+            .into_iter()
+            .enumerate()
+            .for_each(|(id, name)| self.browser_entry(ui, name, Selection::DropTable(id)));
+        ui.separator();
+        ui.heading("Spawners");
+        if ui.add(Button::new("Add Spawner")).clicked() {
+            self.state.add_spawner();
+        }
+        self.state
+            .project_names()
+            .spawners
+            .into_iter()
+            .enumerate()
+            .for_each(|(id, name)| self.browser_entry(ui, name, Selection::Spawner(id)));
+        ui.separator();
+        ui.heading("RandomSpawns");
+        if ui.add(Button::new("Add RandomSpawn")).clicked() {
+            self.state.add_random_spawn();
+        }
+        self.state
+            .random_spawns
+            .iter()
+            .map(|random_spawn| random_spawn.name.clone())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .enumerate()
+            .for_each(|(id, name)| self.browser_entry(ui, name, Selection::RandomSpawn(id)));
+        ui.separator();
+        if self.state.selected.is_some() && ui.add(Button::new("Remove Selected")).clicked() {
+            self.state.remove_selected();
+        }
+    }
+    fn items_browser_ui(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Items");
+        if ui.add(Button::new("Add Item")).clicked() {
+            self.state.add_item();
+        }
+        self.state
+            .items
+            .iter()
+            .map(|item| item.name.clone())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .enumerate()
+            .for_each(|(id, name)| self.browser_entry(ui, name, Selection::Item(id)));
+        ui.separator();
+        if self.state.selected.is_some() && ui.add(Button::new("Remove Selected")).clicked() {
+            self.state.remove_selected();
+        }
+    }
+    fn browser_entry(&mut self, ui: &mut egui::Ui, name: String, entry: Selection) {
+        if self.state.selected == Some(entry) {
+            ui.add(Button::new(name).fill(Color32::from_rgb(127, 127, 127)));
+        } else if ui.add(Button::new(name)).clicked() {
+            self.state.selected = Some(entry);
+        }
+    }
+    fn project_path_text(&self) -> String {
+        self.state
+            .project_path
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "project.ron".to_owned())
+    }
+    fn file_prompt_window(&mut self, ctx: &egui::Context) {
+        let Some(prompt) = &mut self.file_prompt else {
+            return;
+        };
+        let (title, path) = match prompt {
+            FilePrompt::Open(path) => ("Open Project", Some(path)),
+            FilePrompt::SaveAs(path) => ("Save Project As", Some(path)),
+            FilePrompt::ImportMob(path) => ("Import Mob YAML", Some(path)),
+            FilePrompt::Export => ("Export to Pack", None),
+        };
+        let export = &mut self.state.export;
+        let mut confirmed = false;
+        let mut cancelled = false;
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                if let Some(path) = path {
+                    ui.horizontal(|ui| {
+                        ui.label("Path");
+                        ui.text_edit_singleline(path);
+                    });
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.label("Pack Folder").on_hover_ui(|ui| {
+                            ui.label("The pack root, mobs are written in its Mobs folder, metaskills in its Skills folder, droptables in its DropTables folder, items in its Items folder, random spawns in its RandomSpawns folder and spawners in its Spawners folder");
+                        });
+                        ui.text_edit_singleline(&mut export.pack_dir);
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("File Name").on_hover_ui(|ui| {
+                            ui.label("Leave empty to write one file per internal name, or set it to write every entry in the same file. An entry with the same internal name is replaced, the other entries of the file are kept");
+                        });
+                        ui.text_edit_singleline(&mut export.file_name);
+                    });
+                }
+                ui.horizontal(|ui| {
+                    confirmed = ui.button("Ok").clicked();
+                    cancelled = ui.button("Cancel").clicked();
+                });
+            });
+        if confirmed {
+            match self.file_prompt.take() {
+                Some(FilePrompt::Open(path)) => {
+                    let result = self.state.open_project(path);
+                    self.set_status(result, "Project opened");
+                }
+                Some(FilePrompt::SaveAs(path)) => {
+                    let result = self.state.save_project_as(path);
+                    self.set_status(result, "Project saved");
+                }
+                Some(FilePrompt::ImportMob(path)) => {
+                    self.status = match self.state.import_mob_file(path) {
+                        Ok(summary) => summary,
+                        Err(e) => e,
+                    };
+                }
+                Some(FilePrompt::Export) => {
+                    self.status = match self.state.export_to_pack() {
+                        Ok(paths) => format!("Exported {} file(s)", paths.len()),
+                        Err(e) => e,
+                    };
+                }
+                None => {}
+            }
+        } else if cancelled {
+            self.file_prompt = None;
+        }
     }
 }
 
 impl eframe::App for Ui {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::TopBottomPanel::top("Top").show(ctx, |ui| {
+            egui::MenuBar::new().ui(ui, |ui| {
+                self.file_menu(ui);
+            });
             ui.heading("MythicMobs Skill Editor");
-            if ui.add(Button::new("Preview")).clicked() {
-                self.state.create_config();
-            }
+            ui.horizontal(|ui| {
+                if ui.add(Button::new("Preview")).clicked() {
+                    self.state.create_config();
+                }
+                if !self.status.is_empty() {
+                    ui.label(&self.status);
+                }
+            });
+        });
+        self.file_prompt_window(ctx);
+
+        egui::SidePanel::left("BrowserPanel").show(ctx, |ui| {
+            self.browser_ui(ui);
         });
 
+        let is_metaskill = matches!(self.state.selected, Some(Selection::MetaSkill(_)));
         egui::SidePanel::left("SidePanel").show(ctx, |ui| {
+            let droptables = self.state.droptable_names();
+            let elements = self.state.damage_elements();
+            if let Some(droptable) = self.state.selected_droptable_mut() {
+                droptable_ui(ui, droptable, &droptables);
+                return;
+            }
+            let mobs = self.state.mob_names();
+            if let Some(spawner) = self.state.selected_spawner_mut() {
+                spawner.handle_ui(ui, &mobs);
+                ui.separator();
+                conditions_ui(ui, "Conditions", &mut spawner.conditions);
+                return;
+            }
+            if let Some(random_spawn) = self.state.selected_random_spawn_mut() {
+                random_spawn.handle_ui(ui, &mobs);
+                ui.separator();
+                conditions_ui(ui, "Conditions", &mut random_spawn.conditions);
+                return;
+            }
+            if let Some(item) = self.state.selected_item_mut() {
+                item.handle_ui(ui);
+                ui.separator();
+                skill_list_ui(ui, item);
+                return;
+            }
+            if let Some(metaskill) = self.state.selected_metaskill_mut() {
+                metaskill_ui(ui, metaskill);
+                ui.separator();
+                skill_list_ui(ui, metaskill);
+                return;
+            }
+            let Some(mob) = self.state.selected_mob_mut() else {
+                ui.label("Add or select a mob in the project browser");
+                return;
+            };
             ui.horizontal(|ui| {
-                ui.label(if let MinecraftMob::MetaSkill = self.state.mob_type {"Internal Skill Name"} else {"Internal Name"}).on_hover_ui(|ui| {
-                    if let MinecraftMob::MetaSkill = self.state.mob_type {ui.label("It's the string that will identify the metaskill inside mythicmobs, exactly how the [Internal Name] works for mobs.
-A valid Internal SkillName must be unique (aka, there cannot exists two skills that shares the skillname) and not containt any space character.
-If you want to execute a specific metaskill in any way, you will have to use its Internal SkillName in some way");} else {ui.label("This string will be how your mob will be referenced internally in MythicMobs and can be any name you like.
-Must be a unique name and does not clash with other internal mob names, NO SPACES ALLOWED.");}
+                ui.label("Internal Name").on_hover_ui(|ui| {
+                    ui.label("This string will be how your mob will be referenced internally in MythicMobs and can be any name you like.
+Must be a unique name and does not clash with other internal mob names, NO SPACES ALLOWED.");
                 });
-                    ui.text_edit_singleline(&mut self.state.mob_name);
+                    ui.text_edit_singleline(&mut mob.mob_name);
             });
             ui.horizontal(|ui| {
-                if let MinecraftMob::MetaSkill = self.state.mob_type {
-                   self.state.mob_display_name.clear();
-                   self.state.mob_health = 0;
-                   self.state.damage = 0;
-                   self.state.armor = 0; 
-                } else {
                 ui.label("Mob Display Name").on_hover_ui(|ui| {ui.label("Sets the display name of the mob.
 This option supports color codes and placeholders.
 The mob's name will not change or update on its own, you have to use setname mechanic to change or update it.");});
-                ui.text_edit_singleline(&mut self.state.mob_display_name);
-                    
-                }
+                ui.text_edit_singleline(&mut mob.mob_display_name);
             });
             ui.horizontal(|ui| {
                 ui.label("Type").on_hover_ui(|ui| {
-                    if let MinecraftMob::MetaSkill = self.state.mob_type {
-                        ui.label("A Metaskill is, in essence, a list of mechanics to execute once the metaskill is called via a Meta Mechanic.
-They are located in ../plugins/MythicMobs/Skills inside .yml files, just like their mobs counterpart.");
-                    }else {
                     ui.label("This field determines which entity type your creation will be based upon.
 A complete list of available entity types can be found on spigot javadocs, while here you can find a list of types that are explicitly implemented.
 
 Several mob options for new entity types that Minecraft adds to the base game will not function until Mythic adds support for said entity types
 
 
-Some entity types can have negative, hard-to-discover quirks. It is advised that you refer to the unstable entity types page in order to form a better opinion on what entity you should be using for your current endeavor");}
+Some entity types can have negative, hard-to-discover quirks. It is advised that you refer to the unstable entity types page in order to form a better opinion on what entity you should be using for your current endeavor");
 });
                 egui::ComboBox::new("Mob_Type", "")
-                    .selected_text(self.state.mob_type.to_string())
+                    .selected_text(mob.mob_type.to_string())
                     .show_ui(ui, |ui| {
                         MinecraftMob::VARIANTS.iter().for_each(|v| {
-                            ui.selectable_value(&mut self.state.mob_type, v.clone(), v.to_string());
+                            ui.selectable_value(&mut mob.mob_type, v.clone(), v.to_string());
                         });
                     });
             });
             ui.horizontal(|ui| {
-                if let MinecraftMob::MetaSkill = self.state.mob_type {} else {
                 ui.add(
-                    egui::Slider::new(&mut self.state.mob_health, 0..=2048)
+                    egui::Slider::new(&mut mob.mob_health, 0..=2048)
                         .text("Mob Health")
                 ).on_hover_ui(|ui| {
                         ui.label(r"Sets the base value of the mob's max health attribute.
@@ -82,15 +364,10 @@ Mythic doesn't have any limitations on max health but Spigot, however, caps the
 This can easily be changed in spigot's configuration file, server_root\spigot.yml.
 Whenever the mob is holding or wearing an item with attribute modifiers will also affect the total max health.");
                     });
-                    
-                }
             });
             ui.horizontal(|ui| {
-                if let MinecraftMob::MetaSkill = self.state.mob_type {
-
-                } else {
-                                    ui.add(
-                    egui::Slider::new(&mut self.state.damage, 0..=255)
+                ui.add(
+                    egui::Slider::new(&mut mob.damage, 0..=255)
                         .text("Mob Damage")
                         .step_by(1.0),
                 ).on_hover_ui(|ui| {
@@ -98,125 +375,124 @@ Whenever the mob is holding or wearing an item with attribute modifiers will als
 1 damage equals to 0.5 hearts, so a mob with 6 damage will deal 3 full hearts of damage.
 This attribute will never affect damage done by ranged attacks, like arrows or potions.
 Whenever the mob is holding or wearing an item with attribute modifiers will also affect the mob's melee damage.");
-                    });                    
-                }
+                    });
             });
             ui.horizontal(|ui| {
-                if let MinecraftMob::MetaSkill = self.state.mob_type {} else {
-                ui.add(egui::Slider::new(&mut self.state.armor, 0..=255).text("Mob Armor").step_by(1.0)).on_hover_ui(|ui| {
+                ui.add(egui::Slider::new(&mut mob.armor, 0..=255).text("Mob Armor").step_by(1.0)).on_hover_ui(|ui| {
                     ui.label("Sets the base value of the mob's armor attribute.
 Minecraft caps the max armor value to 30.
 Whenever the mob is holding or wearing an item with attribute modifiers will also affect the total armor.");
                 });
-                    
-                }
             });
+            egui::CollapsingHeader::new("Options").id_salt("Mob_Options").show(ui, |ui| {
+                mob.options.handle_ui(ui, &mob.mob_type);
+            });
+            egui::CollapsingHeader::new("BossBar").id_salt("Mob_BossBar").show(ui, |ui| {
+                mob.boss_bar.handle_ui(ui);
+            });
+            egui::CollapsingHeader::new("Equipment").id_salt("Mob_Equipment").show(ui, |ui| {
+                mob.equipment.handle_ui(ui);
+            });
+            drops_ui(ui, "Mob_Drops", &mut mob.drops, &droptables);
+            damage_modifiers_ui(ui, &mut mob.damage_modifiers, &elements);
+            ai_selectors_ui(ui, "AIGoalSelectors", &mut mob.ai_goal_selectors, |ui, goal, id| goal.handle_ui(ui, id));
+            ai_selectors_ui(ui, "AITargetSelectors", &mut mob.ai_target_selectors, |ui, selector, id| selector.handle_ui(ui, id));
             ui.separator();
-            if ui.add(Button::new("Add Skill")).clicked() {
-                self.state.add_skill();
-            }
-            if ui.add(Button::new("Remove Skill")).clicked() {
-                self.state.remove_selected_skill();
-            }
-            if let Some(sk) = self.state.get_selected_skill() {
-                ui.horizontal(|ui| {
-                    ui.label("Skill name");
-                    ui.add(egui::TextEdit::singleline(&mut sk.name));
-                });
-            }
-
-            ui.separator();
-            self.state
-                .skills
-                .clone()
-                .iter()
-                .enumerate()
-                .for_each(|(id, sk)| {
-                    if let Some(selected_id) = self.state.selected_skill {
-                        if id == selected_id {
-                            ui.horizontal(|ui| {
-                                ui.add(
-                                    Button::new(sk.name.clone())
-                                        .fill(Color32::from_rgb(127, 127, 127)),
-                                );
-                                if selected_id != 0 && ui.button("Move Skill Up").clicked() {
-                                    self.state.skills.insert(selected_id - 1, sk.clone());
-                                    self.state.remove_skill(selected_id + 1);
-                                    self.state.select_skill(selected_id - 1);
-                                }
-                                if selected_id != self.state.skills.len() - 1
-                                    && ui.button("Move Skill Down").clicked()
-                                {
-                                    self.state.skills.insert(selected_id + 2, sk.clone());
-                                    self.state.remove_skill(selected_id);
-                                    self.state.select_skill(selected_id + 1);
-                                }
-                            });
-                        } else if ui.add(Button::new(sk.name.clone())).clicked() {
-                            self.state.select_skill(id);
-                        };
-                    }
-                });
+            skill_list_ui(ui, mob);
         });
 
         egui::CentralPanel::default().show(ctx, |ui| {
-            if let Some(selected_id) = self.state.selected_skill {
-                if let Some(sk) = self.state.skills.get_mut(selected_id) {
-                    ui.horizontal(|ui| {
-                        ui.label("Mechanic").on_hover_ui(|ui| {ui.label("Skill Mechanics (or base skills) are simple skills that are built into
+            let names = self.state.project_names();
+            let Some(sk) = self.state.selected_skill_list().and_then(|list| list.get_selected_skill()) else {
+                return;
+            };
+            ui.horizontal(|ui| {
+                ui.label("Mechanic").on_hover_ui(|ui| {ui.label("Skill Mechanics (or base skills) are simple skills that are built into
 MythicMobs. You can call these basic skills by themselves in your mob's
 Skill List, or you can create your own meta-skill by combining these
 mechanics together.
 Some Mechanics are able to target Entities, Locations, or both! Some
 don't target anything. You control what your skill targets using a
 Targeter.");});
-                    egui::ComboBox::new("Mechanic", "")
-                        .selected_text(if let Some(mech) = &sk.mechanic {
-                            mech.get_fields().into()
-                        } else {
-                            "None".to_owned()
-                        })
-                        .show_ui(ui, |ui| {
-                            Mechanic::iter().for_each(|v| {
-                                ui.selectable_value(
-                                    &mut sk.mechanic,
-                                    Some(v.clone()),
-                                    v.get_fields().into(),
-                                );
-                            });
-                            ui.selectable_value(&mut sk.mechanic, None, "None".to_string());
-                            ui.separator();
-                        });                        
+            egui::ComboBox::new("Mechanic", "")
+                .selected_text(if let Some(mech) = &sk.mechanic {
+                    mech.get_fields().into()
+                } else {
+                    "None".to_owned()
+                })
+                .show_ui(ui, |ui| {
+                    Mechanic::iter().for_each(|v| {
+                        let selected = sk.mechanic.as_ref().is_some_and(|m| std::mem::discriminant(m) == std::mem::discriminant(&v));
+                        if ui.selectable_label(selected, v.get_fields().into()).clicked() && !selected {
+                            sk.mechanic = Some(v.with_defaults());
+                        }
                     });
+                    ui.selectable_value(&mut sk.mechanic, None, "None".to_string());
+                    ui.separator();
+                });                        
+                if let Some(missing) = sk.mechanic.as_ref().and_then(Mechanic::missing_value) {
+                    ui.colored_label(Color32::YELLOW, "⚠")
+                        .on_hover_text(format!("{missing} is required, the skill is not exported without it"));
+                }
+            });
 
-                    // Mechanics
-                    if let Some(mechanic) = &mut sk.mechanic {
-                        process_mechanic_ui(ui, mechanic);
-                    } else {
-                        ui.separator();
-                        ui.label("Raw Args");
-                        ui.text_edit_singleline(&mut sk.raw_args);
-                    }
+            // Mechanics
+            if let Some(mechanic) = &mut sk.mechanic {
+                process_mechanic_ui(ui, mechanic, &names);
+            } else {
+                ui.separator();
+                ui.label("Raw Args");
+                ui.text_edit_singleline(&mut sk.raw_args);
+            }
+            // A delay takes nothing but its ticks
+            if let Some(Mechanic::Delay(_)) = sk.mechanic {
+                return;
+            }
 
-                    ui.separator();
-                    ui.horizontal(|ui| {
-                        ui.label("Targeters");
-                    egui::ComboBox::new("Targeters_Selector", "").selected_text(sk.targeter.get_fields().into()).show_ui(ui, |ui| {
-                        Targeter::iter().for_each(|v| {
-                       ui.selectable_value(&mut sk.targeter, v.clone(), v.get_fields().into()); 
-                        });
-                    });
-                        
-                    });
-                    
-                    // Targeter
-                    process_targeter_ui(ui, &mut sk.targeter);
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label("Targeters");
+            egui::ComboBox::new("Targeters_Selector", "").selected_text(sk.targeter.get_fields().into()).show_ui(ui, |ui| {
+                Targeter::iter().for_each(|v| {
+               ui.selectable_value(&mut sk.targeter, v.clone(), v.get_fields().into()); 
+                });
+            });
+                
+            });
+            
+            // Targeter
+            process_targeter_ui(ui, &mut sk.targeter, &names);
 
-                    // Trigger
-                    ui.separator();
-                    ui.horizontal(|ui| {
-                        ui.label("Trigger").on_hover_ui(|ui| {
-                            ui.label("Triggers are used to determine how a skill is triggered from within the
+            // Inline Conditions
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label("Inline Conditions").on_hover_ui(|ui| {
+                    ui.label("Conditions written directly on the skill line.
+?condition checks the target, ?~condition checks the trigger and ?! negates the condition");
+                });
+                if ui.button("Add Condition").clicked() {
+                    sk.conditions.push(Default::default());
+                }
+            });
+            let mut removed = None;
+            sk.conditions.iter_mut().enumerate().for_each(|(id, condition)| {
+                ui.horizontal(|ui| {
+                    condition.handle_ui(ui, ("Inline_Condition", id));
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some(id) = removed {
+                sk.conditions.remove(id);
+            }
+
+            // Trigger, metaskills are cast without one
+            if !is_metaskill {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label("Trigger").on_hover_ui(|ui| {
+                        ui.label("Triggers are used to determine how a skill is triggered from within the
 mobs skill configuration section.
 TRIGGERS CANNOT BE USED IN META-SKILLS AND SHOULD NOT BE INCLUDED
 IN THEM.
@@ -224,17 +500,41 @@ Triggers can only be used to activate the meta-skill
 itself (in the mob's configuration file).
 
 Each trigger starts with a on string. That string is case sensitive, so make sure to write it correctly or the trigger will not work");
-                        });
-                    egui::ComboBox::new("Trigger_Dropdown_Menu", "").selected_text(sk.trigger.get_fields().into()).show_ui(ui, |ui| {
-                        Trigger::VARIANTS.iter().for_each(|v| {
-                            ui.selectable_value(&mut sk.trigger, v.clone(), v.get_fields().into());
-                        });
                     });
- 
+                egui::ComboBox::new("Trigger_Dropdown_Menu", "").selected_text(sk.trigger.get_fields().into()).show_ui(ui, |ui| {
+                    Trigger::iter().for_each(|v| {
+                        let selected = std::mem::discriminant(&sk.trigger) == std::mem::discriminant(&v);
+                        if ui.selectable_label(selected, v.get_fields().into()).clicked() && !selected {
+                            sk.trigger = v;
+                        }
                     });
+                });
+ 
+                });
 
-                }
+                process_trigger_ui(ui, &mut sk.trigger);
+            }
+
+            // Modifiers
+            ui.separator();
+            ui.label("Modifiers");
+            if sk.mechanic.is_some() {
+                sk.options.handle_ui(ui);
             }
+            ui.horizontal(|ui| {
+                optional_value(
+                    ui,
+                    "Health Modifier",
+                    "Only run the skill when the health of the caster is in this range",
+                    &mut sk.health_modifier,
+                    HealthModifier::default,
+                    |ui, health_modifier| health_modifier.handle_ui(ui),
+                );
+            });
+            ui.horizontal(|ui| {
+                optional_chance(ui, "Chance", "Chance for the skill to run, from 0 to 1", &mut sk.chance);
+            });
+
         });
         egui::TopBottomPanel::bottom("output_yaml_panel").show(ctx, |ui| {
             if self.state.output.is_empty() {
@@ -245,14 +545,355 @@ Each trigger starts with a on string. That string is case sensitive, so make sur
     }
 }
 
+/// Keys of the selected metaskill
+fn metaskill_ui(ui: &mut egui::Ui, metaskill: &mut MetaSkill) {
+    ui.horizontal(|ui| {
+        ui.label("Internal Skill Name").on_hover_ui(|ui| {
+            ui.label("It's the string that will identify the metaskill inside mythicmobs, exactly how the [Internal Name] works for mobs.
+A valid Internal SkillName must be unique (aka, there cannot exists two skills that shares the skillname) and not containt any space character.
+If you want to execute a specific metaskill in any way, you will have to use its Internal SkillName in some way");
+        });
+        ui.text_edit_singleline(&mut metaskill.name);
+    });
+    ui.horizontal(|ui| {
+        ui.label("Cooldown").on_hover_ui(|ui| {
+            ui.label("Seconds before the metaskill can be cast again, 0 for none");
+        });
+        ui.add(egui::DragValue::new(&mut metaskill.cooldown).speed(0.1).range(0.0..=f32::MAX));
+    });
+    ui.checkbox(&mut metaskill.cancel_if_no_targets, "Cancel If No Targets").on_hover_ui(|ui| {
+        ui.label("Don't cast the metaskill when its targeter finds no target");
+    });
+    ui.horizontal(|ui| {
+        ui.label("On Cooldown Skill").on_hover_ui(|ui| {
+            ui.label("Metaskill cast instead while this one is on cooldown");
+        });
+        ui.text_edit_singleline(&mut metaskill.on_cooldown_skill);
+    });
+    ui.horizontal(|ui| {
+        ui.label("Failed Conditions Skill").on_hover_ui(|ui| {
+            ui.label("Metaskill cast instead when the conditions are not met");
+        });
+        ui.text_edit_singleline(&mut metaskill.failed_conditions_skill);
+    });
+    ui.checkbox(&mut metaskill.spell, "Spell").on_hover_ui(|ui| {
+        ui.label("Fire the spell cast events so that other plugins can react to the metaskill");
+    });
+    ui.separator();
+    conditions_ui(ui, "Conditions", &mut metaskill.conditions);
+    conditions_ui(ui, "TargetConditions", &mut metaskill.target_conditions);
+    conditions_ui(ui, "TriggerConditions", &mut metaskill.trigger_conditions);
+}
+
+fn droptable_ui(ui: &mut egui::Ui, droptable: &mut DropTable, droptables: &[String]) {
+    ui.horizontal(|ui| {
+        ui.label("Internal Name").on_hover_ui(|ui| {
+            ui.label("The name used to reference the droptable in the drops of a mob or another droptable");
+        });
+        ui.text_edit_singleline(&mut droptable.name);
+    });
+    [
+        ("Total Items", "Exact number of drops picked from the table", &mut droptable.total_items),
+        ("Min Items", "Minimum number of drops picked from the table", &mut droptable.min_items),
+        ("Max Items", "Maximum number of drops picked from the table", &mut droptable.max_items),
+    ]
+    .into_iter()
+    .for_each(|(label, hover, value)| {
+        ui.horizontal(|ui| {
+            optional_value(ui, label, hover, value, || 1, |ui, value| {
+                ui.add(egui::DragValue::new(value));
+            });
+        });
+    });
+    ui.separator();
+    conditions_ui(ui, "Conditions", &mut droptable.conditions);
+    // A droptable can't reference itself
+    let others: Vec<String> = droptables
+        .iter()
+        .filter(|name| **name != droptable.name)
+        .cloned()
+        .collect();
+    drops_ui(ui, "DropTable_Drops", &mut droptable.drops, &others);
+}
+
+/// Editable drop list of a mob or droptable
+fn drops_ui(ui: &mut egui::Ui, id_salt: &str, drops: &mut Vec<DropLine>, droptables: &[String]) {
+    egui::CollapsingHeader::new(format!("Drops ({})", drops.len()))
+        .id_salt(id_salt)
+        .show(ui, |ui| {
+            let mut removed = None;
+            drops.iter_mut().enumerate().for_each(|(id, drop)| {
+                ui.horizontal(|ui| {
+                    drop.handle_ui(ui, (id_salt, id), droptables);
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some(id) = removed {
+                drops.remove(id);
+            }
+            if ui.button("Add Drop").clicked() {
+                drops.push(DropLine::default());
+            }
+        });
+}
+
+/// Editable resistances of a mob
+fn damage_modifiers_ui(ui: &mut egui::Ui, modifiers: &mut Vec<DamageModifier>, elements: &[String]) {
+    egui::CollapsingHeader::new(format!("DamageModifiers ({})", modifiers.len()))
+        .id_salt("Mob_DamageModifiers")
+        .show(ui, |ui| {
+            let mut removed = None;
+            modifiers.iter_mut().enumerate().for_each(|(id, modifier)| {
+                ui.horizontal(|ui| {
+                    modifier.handle_ui(ui, ("damage_modifier", id), elements);
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some(id) = removed {
+                modifiers.remove(id);
+            }
+            if ui.button("Add Modifier").clicked() {
+                modifiers.push(DamageModifier::default());
+            }
+        });
+}
+
+/// Editable AI selector list of a mob, in priority order
+fn ai_selectors_ui<T: Default>(
+    ui: &mut egui::Ui,
+    section: &str,
+    selectors: &mut Vec<T>,
+    selector_ui: impl Fn(&mut egui::Ui, &mut T, (&str, usize)),
+) {
+    egui::CollapsingHeader::new(format!("{section} ({})", selectors.len()))
+        .id_salt(section)
+        .show(ui, |ui| {
+            let mut removed = None;
+            let mut moved = None;
+            let last = selectors.len().saturating_sub(1);
+            selectors.iter_mut().enumerate().for_each(|(id, selector)| {
+                ui.horizontal(|ui| {
+                    selector_ui(ui, selector, (section, id));
+                    if id != 0 && ui.button("Up").clicked() {
+                        moved = Some((id, id - 1));
+                    }
+                    if id != last && ui.button("Down").clicked() {
+                        moved = Some((id, id + 1));
+                    }
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some((from, to)) = moved {
+                selectors.swap(from, to);
+            }
+            if let Some(id) = removed {
+                selectors.remove(id);
+            }
+            if ui.button("Add Selector").clicked() {
+                selectors.push(T::default());
+            }
+        });
+}
+
+/// Skill list of the selected mob, metaskill or item
+fn skill_list_ui(ui: &mut egui::Ui, list: &mut impl SkillList) {
+    if ui.add(Button::new("Add Skill")).clicked() {
+        list.add_skill();
+    }
+    if ui.add(Button::new("Remove Skill")).clicked() {
+        list.remove_selected_skill();
+    }
+    if let Some(sk) = list.get_selected_skill() {
+        ui.horizontal(|ui| {
+            ui.label("Skill name");
+            ui.add(egui::TextEdit::singleline(&mut sk.name));
+        });
+    }
+
+    ui.separator();
+    list
+        .skills()
+        .clone()
+        .iter()
+        .enumerate()
+        .for_each(|(id, sk)| {
+            if let Some(selected_id) = *list.selected_skill() {
+                if id == selected_id {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            Button::new(sk.name.clone())
+                                .fill(Color32::from_rgb(127, 127, 127)),
+                        );
+                        if selected_id != 0 && ui.button("Move Skill Up").clicked() {
+                            list.skills().insert(selected_id - 1, sk.clone());
+                            list.remove_skill(selected_id + 1);
+                            list.select_skill(selected_id - 1);
+                        }
+                        if selected_id != list.skills().len() - 1
+                            && ui.button("Move Skill Down").clicked()
+                        {
+                            list.skills().insert(selected_id + 2, sk.clone());
+                            list.remove_skill(selected_id);
+                            list.select_skill(selected_id + 1);
+                        }
+                    });
+                } else if ui.add(Button::new(sk.name.clone())).clicked() {
+                    list.select_skill(id);
+                };
+            }
+        });
+}
+
+/// Editable list of a metaskill condition section
+fn conditions_ui(ui: &mut egui::Ui, section: &str, conditions: &mut Vec<SkillCondition>) {
+    egui::CollapsingHeader::new(format!("{section} ({})", conditions.len()))
+        .id_salt(section)
+        .show(ui, |ui| {
+            let mut removed = None;
+            conditions.iter_mut().enumerate().for_each(|(id, condition)| {
+                ui.horizontal(|ui| {
+                    condition.handle_ui(ui, (section, id));
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some(id) = removed {
+                conditions.remove(id);
+            }
+            if ui.button("Add Condition").clicked() {
+                conditions.push(SkillCondition::default());
+            }
+        });
+}
+
 fn process_trigger_ui(ui: &mut egui::Ui, trigger: &mut crate::skill::triggers::Trigger)  {
+    ui.horizontal(|ui| match trigger {
+        Trigger::Timer(interval) => {
+            ui.label("Interval").on_hover_ui(|ui| {
+                ui.label("The interval in ticks, 20 ticks are 1 second");
+            });
+            ui.add(egui::DragValue::new(interval).range(1..=u32::MAX));
+        }
+        Trigger::Signal(signal) => {
+            ui.label("Signal").on_hover_ui(|ui| {
+                ui.label("The signal sent with the signal mechanic");
+            });
+            ui.text_edit_singleline(signal);
+        }
+        Trigger::Damaged(cause) => {
+            optional_trigger_arg(ui, "Damage Cause", "Only trigger on this damage cause, ex: ENTITY_ATTACK", cause);
+        }
+        Trigger::Interact(hand) => {
+            optional_trigger_arg(ui, "Hand", "Only trigger on this hand, HAND or OFF_HAND", hand);
+        }
+        Trigger::Hear(sound) => {
+            optional_trigger_arg(ui, "Sound", "Only trigger on this sound type, ex: STEP", sound);
+        }
+        Trigger::None => {
+            ui.label("Mob skills without a trigger run ~onCombat");
+        }
+        _ => {}
+    });
+}
+
+fn optional_trigger_arg(ui: &mut egui::Ui, label: &str, hover: &str, arg: &mut MythicOption<String>) {
+    optional_argument(ui, label, hover, arg, ":", String::new, |ui, value| {
+        ui.text_edit_singleline(value);
+    });
+}
+
+/// Optional metaskill argument of a mechanic, written as `;key=MetaSkill`
+fn metaskill_option_ui(
+    ui: &mut egui::Ui,
+    label: &str,
+    hover: &str,
+    key: &str,
+    option: &mut MythicOption<String>,
+    metaskills: &[String],
+) {
+    ui.horizontal(|ui| {
+        optional_argument(
+            ui,
+            label,
+            hover,
+            option,
+            &format!(";{key}="),
+            || metaskills.first().cloned().unwrap_or_default(),
+            |ui, name| metaskill_picker(ui, (label, key), name, metaskills),
+        );
+    });
 }
 
-fn process_targeter_ui(ui: &mut egui::Ui, targeter: &mut crate::skill::targeters::Targeter){
+/// Hit radiuses and hit targets shared by the projectile mechanics
+fn projectile_hit_ui(ui: &mut egui::Ui, hit_radius: &mut f32, vertical_hit_radius: &mut f32, hit_players: &mut bool, hit_non_players: &mut bool) {
+    ui.horizontal(|ui| {
+        ui.label("Hit Radius").on_hover_ui(|ui| {ui.label("Horizontal radius in which entities are hit");});
+        ui.add(egui::DragValue::new(hit_radius).speed(0.1));
+        ui.label("Vertical Hit Radius").on_hover_ui(|ui| {ui.label("Vertical radius in which entities are hit");});
+        ui.add(egui::DragValue::new(vertical_hit_radius).speed(0.1));
+    });
+    ui.horizontal(|ui| {
+        ui.checkbox(hit_players, "Hit Players");
+        ui.checkbox(hit_non_players, "Hit Non Players");
+    });
+}
+
+/// Optional `bulletType` of the projectile mechanics, the default bullet when disabled
+fn bullet_type_ui(ui: &mut egui::Ui, bullet_type: &mut MythicOption<BulletType>) {
+    ui.horizontal(|ui| {
+        optional_argument(
+            ui,
+            "Bullet Type",
+            "The entity or item shown as the projectile",
+            bullet_type,
+            ";bulletType=",
+            BulletType::default,
+            |ui, bullet| {
+                egui::ComboBox::new("Bullet_Type", "")
+                    .selected_text(bullet.to_string())
+                    .show_ui(ui, |ui| {
+                        BulletType::iter().for_each(|v| {
+                            let label = v.to_string();
+                            ui.selectable_value(bullet, v, label);
+                        });
+                    });
+            },
+        );
+    });
+}
+
+/// Metaskills run by the projectile mechanics
+fn projectile_skills_ui(ui: &mut egui::Ui, skills: &mut ProjectileSkills, metaskills: &[String]) {
+    metaskill_option_ui(ui, "On Start Skill", "Metaskill run when the projectile starts", "onStart", &mut skills.on_start, metaskills);
+    metaskill_option_ui(ui, "On Tick Skill", "Metaskill run every interval", "onTick", &mut skills.on_tick, metaskills);
+    metaskill_option_ui(ui, "On Hit Skill", "Metaskill run on each entity hit", "onHit", &mut skills.on_hit, metaskills);
+    metaskill_option_ui(ui, "On End Skill", "Metaskill run when the projectile ends", "onEnd", &mut skills.on_end, metaskills);
+}
+
+/// Pick a metaskill of the project or type the name of another one
+fn metaskill_picker(ui: &mut egui::Ui, id_salt: impl std::hash::Hash, name: &mut String, metaskills: &[String]) {
+    egui::ComboBox::new(("metaskill_picker", id_salt), "")
+        .selected_text(name.clone())
+        .show_ui(ui, |ui| {
+            metaskills.iter().for_each(|metaskill| {
+                ui.selectable_value(name, metaskill.clone(), metaskill);
+            });
+        });
+    ui.text_edit_singleline(name);
+}
+
+fn process_targeter_ui(ui: &mut egui::Ui, targeter: &mut crate::skill::targeters::Targeter, names: &ProjectNames){
     ui.horizontal(|ui| {
 
     match targeter {
-        crate::skill::targeters::Targeter::SingleEntity(single_entity_target) => {
+        crate::skill::targeters::Targeter::SingleEntity(single_entity_target, _) => {
             ui.label("Single Entity:");
 
             egui::ComboBox::new("Single_Entity_Target_Dropdown_Menu", "").selected_text(single_entity_target.to_string()).show_ui(ui, |ui| {
@@ -263,7 +904,7 @@ fn process_targeter_ui(ui: &mut egui::Ui, targeter: &mut crate::skill::targeters
                 
             });
         },
-        crate::skill::targeters::Targeter::MultyEntity(multy_entity) => {
+        crate::skill::targeters::Targeter::MultyEntity(multy_entity, _) => {
             ui.label("Mutli Entity:");
             egui::ComboBox::new("Multi_Entity_Target_Dropdown_Menu", "").selected_text(multy_entity.to_string()).show_ui(ui, |ui| {
                 MultyEntity::VARIANTS.iter().for_each(|v| {
@@ -271,20 +912,43 @@ fn process_targeter_ui(ui: &mut egui::Ui, targeter: &mut crate::skill::targeters
                 });
             });
         },
-        crate::skill::targeters::Targeter::ThreatTable => {},
+        crate::skill::targeters::Targeter::ThreatTable(threat_table_target, _) => {
+            ui.label("Threat Table:");
+            egui::ComboBox::new("Threat_Table_Target_Dropdown_Menu", "").selected_text(threat_table_target.to_string()).show_ui(ui, |ui| {
+                ThreatTableTarget::VARIANTS.iter().for_each(|v| {
+                    ui.selectable_value(threat_table_target, v.clone(), v.to_string());
+                });
+            });
+        },
+        crate::skill::targeters::Targeter::Location(_) => {},
         crate::skill::targeters::Targeter::None => {},
         }
     });
+    if let Targeter::Location(location_target) = targeter {
+        location_target.handle_ui(ui, names);
+    }
+
+    // Options
+    let accepted = match targeter {
+        Targeter::SingleEntity(single_entity_target, options) => Some((single_entity_target.options(), options)),
+        Targeter::MultyEntity(multy_entity, options) => Some((multy_entity.options(), options)),
+        Targeter::ThreatTable(threat_table_target, options) => Some((threat_table_target.options(), options)),
+        Targeter::Location(_) | Targeter::None => None,
+    };
+    if let Some((accepted, options)) = accepted {
+        options.retain(accepted);
+        options.handle_ui(ui, accepted);
+    }
 }
 
 /// Display the mechanic parameters as ui
-fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
+fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic, names: &ProjectNames) {
     ui.heading("Description");
     ui.label(mechanic.get_desc().into());
     ui.separator();
     match mechanic {
         Mechanic::ActivateSpawner { spawner } => {
-            spawner.handle_ui(ui);
+            spawner.handle_ui(ui, names);
         }
         Mechanic::AddTrade {
             action,
@@ -507,6 +1171,46 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
                 ui.checkbox(can_pickup, "Can_Pickup_Checkbox");
             });
         },
+        Mechanic::Aura {
+            aura_name,
+            duration,
+            interval,
+            max_stacks,
+            charges,
+            merge_same_caster,
+            refresh_duration,
+            cancel_on_death,
+            cancel_on_teleport,
+            cancel_on_quit,
+            on_start,
+            on_tick,
+            on_end,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Aura Name").on_hover_ui(|ui| {ui.label("The name of the aura, used to check or remove it");});
+                ui.text_edit_singleline(aura_name);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Duration").on_hover_ui(|ui| {ui.label("How long the aura lasts in ticks");});
+                ui.add(egui::DragValue::new(duration));
+                ui.label("Interval").on_hover_ui(|ui| {ui.label("Ticks between two runs of the tick skill");});
+                ui.add(egui::DragValue::new(interval).range(1..=u32::MAX));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Max Stacks").on_hover_ui(|ui| {ui.label("How many times the aura can stack on the same target");});
+                ui.add(egui::DragValue::new(max_stacks));
+                ui.label("Charges").on_hover_ui(|ui| {ui.label("How many times the aura can be used before it ends, 0 for unlimited");});
+                ui.add(egui::DragValue::new(charges));
+            });
+            ui.checkbox(merge_same_caster, "Merge Same Caster").on_hover_ui(|ui| {ui.label("Merge the auras of the same caster into one aura");});
+            ui.checkbox(refresh_duration, "Refresh Duration").on_hover_ui(|ui| {ui.label("Reapplying the aura resets its duration");});
+            ui.checkbox(cancel_on_death, "Cancel On Death").on_hover_ui(|ui| {ui.label("End the aura when the target dies");});
+            ui.checkbox(cancel_on_teleport, "Cancel On Teleport").on_hover_ui(|ui| {ui.label("End the aura when the target teleports");});
+            ui.checkbox(cancel_on_quit, "Cancel On Quit").on_hover_ui(|ui| {ui.label("End the aura when the target leaves the server");});
+            metaskill_option_ui(ui, "On Start Skill", "Metaskill run when the aura starts", "onStart", on_start, &names.metaskills);
+            metaskill_option_ui(ui, "On Tick Skill", "Metaskill run every interval", "onTick", on_tick, &names.metaskills);
+            metaskill_option_ui(ui, "On End Skill", "Metaskill run when the aura ends", "onEnd", on_end, &names.metaskills);
+        }
         Mechanic::AuraRemove { aura_name, stacks } => {
             ui.horizontal(|ui|{
                 ui.text_edit_singleline(aura_name).on_hover_ui(|ui|{ui.label("The name of the aura to remove.");});
@@ -690,6 +1394,7 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
             do_fake_looting,
             items,
         } => (),
+        Mechanic::CancelEvent => (),
         Mechanic::ClearExperience => (),
         Mechanic::ClearExperienceLevels => (),
         Mechanic::GiveExperienceLevels { amount } => (),
@@ -755,6 +1460,12 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
             current_health,
         } => (),
         Mechanic::Decapitate => (),
+        Mechanic::Delay(ticks) => {
+            ui.horizontal(|ui| {
+                ui.label("Ticks").on_hover_ui(|ui| {ui.label("The number of ticks to wait, 20 ticks are 1 second");});
+                ui.add(egui::DragValue::new(ticks));
+            });
+        }
         Mechanic::Doppleganger {
             has_nameplate,
             use_player_name,
@@ -771,7 +1482,11 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::EnderDragonSetPhase { phase } => (),
         Mechanic::EnderDragonSetRespawnPhase { phase } => (),
         Mechanic::EnderDragonSpawnPortal { with_portals } => (),
-        Mechanic::Equip { item } => (),
+        Mechanic::Equip { item } => {
+            ui.horizontal(|ui| {
+                item.handle_ui(ui, "Equip");
+            });
+        }
         Mechanic::EquipCopy { slots } => (),
         Mechanic::Explosion {
             power_explosion,
@@ -814,6 +1529,12 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         } => (),
         Mechanic::Flames => (),
         Mechanic::Fly => (),
+        Mechanic::ForEach { skill } => {
+            ui.horizontal(|ui| {
+                ui.label("Skill").on_hover_ui(|ui| {ui.label("The metaskill run for each target");});
+                metaskill_picker(ui, "ForEach_Skill", skill, &names.metaskills);
+            });
+        }
         Mechanic::ForcePull { spread, v_spread } => (),
         Mechanic::Freeze { ticks } => (),
         Mechanic::Geyser {
@@ -895,6 +1616,36 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         } => (),
         Mechanic::MatchRotation { target } => (),
         Mechanic::Message { message, audience } => (),
+        Mechanic::Missile {
+            velocity,
+            interval,
+            hit_radius,
+            vertical_hit_radius,
+            max_range,
+            inertia,
+            hit_players,
+            hit_non_players,
+            bullet_type,
+            skills,
+            hit_conditions,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Velocity").on_hover_ui(|ui| {ui.label("Blocks travelled per second");});
+                ui.add(egui::DragValue::new(velocity).speed(0.1));
+                ui.label("Interval").on_hover_ui(|ui| {ui.label("Ticks between two updates of the missile");});
+                ui.add(egui::DragValue::new(interval).range(1..=u32::MAX));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Max Range").on_hover_ui(|ui| {ui.label("Blocks travelled before the missile ends");});
+                ui.add(egui::DragValue::new(max_range));
+                ui.label("Inertia").on_hover_ui(|ui| {ui.label("How slowly the missile turns towards the target");});
+                ui.add(egui::DragValue::new(inertia).speed(0.1));
+            });
+            projectile_hit_ui(ui, hit_radius, vertical_hit_radius, hit_players, hit_non_players);
+            bullet_type_ui(ui, bullet_type);
+            projectile_skills_ui(ui, skills, &names.metaskills);
+            conditions_ui(ui, "Hit Conditions", &mut hit_conditions.0);
+        }
         Mechanic::ModifyDamage {
             amount,
             damage_type,
@@ -925,6 +1676,36 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::MountTarget { entity } => (),
         Mechanic::MovePin { x, y, z } => (),
         Mechanic::OpenTrades => (),
+        Mechanic::Orbital {
+            radius,
+            points,
+            duration,
+            interval,
+            hit_radius,
+            vertical_hit_radius,
+            hit_players,
+            hit_non_players,
+            bullet_type,
+            skills,
+            hit_conditions,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Radius").on_hover_ui(|ui| {ui.label("Distance between the target and the orbiting projectiles");});
+                ui.add(egui::DragValue::new(radius).speed(0.1));
+                ui.label("Points").on_hover_ui(|ui| {ui.label("Number of points in a full orbit");});
+                ui.add(egui::DragValue::new(points).range(1..=u32::MAX));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Duration").on_hover_ui(|ui| {ui.label("How long the orbital lasts in ticks");});
+                ui.add(egui::DragValue::new(duration));
+                ui.label("Interval").on_hover_ui(|ui| {ui.label("Ticks between two moves of the projectiles");});
+                ui.add(egui::DragValue::new(interval).range(1..=u32::MAX));
+            });
+            projectile_hit_ui(ui, hit_radius, vertical_hit_radius, hit_players, hit_non_players);
+            bullet_type_ui(ui, bullet_type);
+            projectile_skills_ui(ui, skills, &names.metaskills);
+            conditions_ui(ui, "Hit Conditions", &mut hit_conditions.0);
+        }
         Mechanic::Oxygen { amount } => (),
         Mechanic::Particle {
             particle,
@@ -1043,6 +1824,39 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::PotionClear => (),
         Mechanic::Prison => (),
         Mechanic::PrintParentTree => (),
+        Mechanic::Projectile {
+            velocity,
+            interval,
+            hit_radius,
+            vertical_hit_radius,
+            max_range,
+            gravity,
+            start_y_offset,
+            hit_players,
+            hit_non_players,
+            bullet_type,
+            skills,
+            hit_conditions,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Velocity").on_hover_ui(|ui| {ui.label("Blocks travelled per second");});
+                ui.add(egui::DragValue::new(velocity).speed(0.1));
+                ui.label("Interval").on_hover_ui(|ui| {ui.label("Ticks between two updates of the projectile");});
+                ui.add(egui::DragValue::new(interval).range(1..=u32::MAX));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Max Range").on_hover_ui(|ui| {ui.label("Blocks travelled before the projectile ends");});
+                ui.add(egui::DragValue::new(max_range));
+                ui.label("Gravity").on_hover_ui(|ui| {ui.label("Blocks the projectile falls per second");});
+                ui.add(egui::DragValue::new(gravity).speed(0.1));
+                ui.label("Start Y Offset").on_hover_ui(|ui| {ui.label("Height above the caster the projectile starts from");});
+                ui.add(egui::DragValue::new(start_y_offset).speed(0.1));
+            });
+            projectile_hit_ui(ui, hit_radius, vertical_hit_radius, hit_players, hit_non_players);
+            bullet_type_ui(ui, bullet_type);
+            projectile_skills_ui(ui, skills, &names.metaskills);
+            conditions_ui(ui, "Hit Conditions", &mut hit_conditions.0);
+        }
         Mechanic::Propel { velocity } => (),
         Mechanic::Pull { velocity } => (),
         Mechanic::PushBlock { velocity } => (),
@@ -1051,6 +1865,29 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::RayTraceTo => (),
         Mechanic::Rally { radius } => (),
         Mechanic::RandomMessage { messages } => (),
+        Mechanic::RandomSkill { skills } => {
+            let total: f32 = skills.0.iter().map(|s| s.weight).sum();
+            let mut removed = None;
+            skills.0.iter_mut().enumerate().for_each(|(id, weighted)| {
+                ui.horizontal(|ui| {
+                    metaskill_picker(ui, ("RandomSkill_Skill", id), &mut weighted.skill, &names.metaskills);
+                    ui.label("Weight").on_hover_ui(|ui| {
+                        let chance = if total > 0.0 { weighted.weight / total * 100.0 } else { 0.0 };
+                        ui.label(format!("Picked {chance:.1}% of the time"));
+                    });
+                    ui.add(egui::DragValue::new(&mut weighted.weight).speed(0.05).range(0.0..=f32::MAX));
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some(id) = removed {
+                skills.0.remove(id);
+            }
+            if ui.button("Add Skill").clicked() {
+                skills.0.push(WeightedSkill::default());
+            }
+        }
         Mechanic::Recoil { velocity } => (),
         Mechanic::Remount => (),
         Mechanic::Remove => (),
@@ -1115,15 +1952,78 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::SetScore { objective, score } => (),
         Mechanic::SetSpeed { speed } => (),
         Mechanic::SetStance { stance } => (),
+        Mechanic::SetVariable {
+            scope,
+            name,
+            variable_type,
+            value,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Variable").on_hover_ui(|ui| {ui.label("The scope and the name of the variable");});
+                egui::ComboBox::new("SetVariable_Scope", "")
+                    .selected_text(scope.to_string())
+                    .show_ui(ui, |ui| {
+                        VariableScope::iter().for_each(|v| {
+                            let label = v.to_string();
+                            ui.selectable_value(scope, v, label);
+                        });
+                    });
+                ui.text_edit_singleline(name);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Type");
+                egui::ComboBox::new("SetVariable_Type", "")
+                    .selected_text(variable_type.to_string())
+                    .show_ui(ui, |ui| {
+                        VariableType::iter().for_each(|v| {
+                            let label = v.to_string();
+                            ui.selectable_value(variable_type, v, label);
+                        });
+                    });
+                ui.label("Value").on_hover_ui(|ui| {ui.label("The value to set, supports placeholders");});
+                ui.text_edit_singleline(value);
+            });
+        }
         Mechanic::Shield => (),
         Mechanic::ShieldBreak => (),
         Mechanic::ShieldPercent { percent } => (),
+        Mechanic::Shoot {
+            projectile_type,
+            velocity,
+            damage,
+            on_hit,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Type").on_hover_ui(|ui| {ui.label("The vanilla projectile to shoot");});
+                egui::ComboBox::new("Shoot_Type", "")
+                    .selected_text(projectile_type.to_string())
+                    .show_ui(ui, |ui| {
+                        ShootType::iter().for_each(|v| {
+                            let label = v.to_string();
+                            ui.selectable_value(projectile_type, v, label);
+                        });
+                    });
+            });
+            ui.horizontal(|ui| {
+                ui.label("Velocity").on_hover_ui(|ui| {ui.label("The velocity of the projectile");});
+                ui.add(egui::DragValue::new(velocity).speed(0.1));
+                ui.label("Damage").on_hover_ui(|ui| {ui.label("The damage dealt by the projectile");});
+                ui.add(egui::DragValue::new(damage).speed(0.1));
+            });
+            metaskill_option_ui(ui, "On Hit Skill", "Metaskill run when the projectile hits", "onHit", on_hit, &names.metaskills);
+        }
         Mechanic::ShootFireball { velocity } => (),
         Mechanic::ShootPotion { potion, velocity } => (),
         Mechanic::ShootSkull { velocity } => (),
         Mechanic::ShootShulkerBullet { velocity } => (),
         Mechanic::ShowEntity { entity } => (),
         Mechanic::Signal { signal } => (),
+        Mechanic::Skill { skill } => {
+            ui.horizontal(|ui| {
+                ui.label("Skill").on_hover_ui(|ui| {ui.label("The metaskill to run");});
+                metaskill_picker(ui, "Skill_Skill", skill, &names.metaskills);
+            });
+        }
         Mechanic::Skybox { skybox } => (),
         Mechanic::Smoke => (),
         Mechanic::SmokeSwirl => (),
@@ -1156,6 +2056,12 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::Stun { duration } => (),
         Mechanic::StopUsingItem => (),
         Mechanic::Suicide => (),
+        Mechanic::SudoSkill { skill } => {
+            ui.horizontal(|ui| {
+                ui.label("Skill").on_hover_ui(|ui| {ui.label("The metaskill the target runs as if it was the caster");});
+                metaskill_picker(ui, "SudoSkill_Skill", skill, &names.metaskills);
+            });
+        }
         Mechanic::Summon { mob, location } => (),
         Mechanic::SummonAreaEffectCloud {
             particle,
@@ -1171,6 +2077,30 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         Mechanic::SummonFallingBlock { material } => (),
         Mechanic::SummonPassenger { passenger, stack } => (),
         Mechanic::Swap => (),
+        Mechanic::Switch { cases } => {
+            let mut removed = None;
+            cases.cases.iter_mut().enumerate().for_each(|(id, case)| {
+                ui.horizontal(|ui| {
+                    ui.label("Case");
+                    case.condition.handle_ui(ui, ("Switch_Condition", id));
+                    ui.label("Skill");
+                    metaskill_picker(ui, ("Switch_Skill", id), &mut case.skill, &names.metaskills);
+                    if ui.button("Remove").clicked() {
+                        removed = Some(id);
+                    }
+                });
+            });
+            if let Some(id) = removed {
+                cases.cases.remove(id);
+            }
+            if ui.button("Add Case").clicked() {
+                cases.cases.push(SwitchCase::default());
+            }
+            ui.horizontal(|ui| {
+                ui.label("Default Skill").on_hover_ui(|ui| {ui.label("Metaskill run when no condition is met, none when empty");});
+                metaskill_picker(ui, "Switch_Default", &mut cases.default, &names.metaskills);
+            });
+        }
         Mechanic::SwingOffHand => (),
         Mechanic::AddTag(_) => (),
         Mechanic::RemoveTag(_) => (),
@@ -1224,6 +2154,36 @@ fn process_mechanic_ui(ui: &mut egui::Ui, mechanic: &mut Mechanic) {
         } => (),
         Mechanic::TogglePiston => (),
         Mechanic::ToggleSitting(_) => (),
+        Mechanic::Totem {
+            max_duration,
+            interval,
+            hit_radius,
+            vertical_hit_radius,
+            y_offset,
+            charges,
+            hit_players,
+            hit_non_players,
+            bullet_type,
+            skills,
+            hit_conditions,
+        } => {
+            ui.horizontal(|ui| {
+                ui.label("Max Duration").on_hover_ui(|ui| {ui.label("How long the totem lasts in ticks");});
+                ui.add(egui::DragValue::new(max_duration));
+                ui.label("Interval").on_hover_ui(|ui| {ui.label("Ticks between two runs of the tick skill");});
+                ui.add(egui::DragValue::new(interval).range(1..=u32::MAX));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Y Offset").on_hover_ui(|ui| {ui.label("Height of the totem above the target location");});
+                ui.add(egui::DragValue::new(y_offset).speed(0.1));
+                ui.label("Charges").on_hover_ui(|ui| {ui.label("How many hits end the totem, 0 for unlimited");});
+                ui.add(egui::DragValue::new(charges));
+            });
+            projectile_hit_ui(ui, hit_radius, vertical_hit_radius, hit_players, hit_non_players);
+            bullet_type_ui(ui, bullet_type);
+            projectile_skills_ui(ui, skills, &names.metaskills);
+            conditions_ui(ui, "Hit Conditions", &mut hit_conditions.0);
+        }
         Mechanic::TotemOfUndying { model } => (),
         Mechanic::TrackLocation => (),
         Mechanic::UndoPaste { paste_id } => (),